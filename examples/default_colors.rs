@@ -0,0 +1,34 @@
+extern crate pancurses;
+
+use pancurses::{
+    endwin, has_colors, init_pair, initscr, noecho, start_color, use_default_colors, COLOR_BLUE,
+    COLOR_PAIR, COLOR_RED, COLOR_YELLOW, OK,
+};
+
+fn main() {
+    let window = initscr();
+    noecho();
+
+    if has_colors() {
+        start_color();
+    }
+    let default_background = has_colors() && use_default_colors() == OK;
+    let background = if default_background { -1 } else { COLOR_BLUE };
+
+    init_pair(1, COLOR_YELLOW, background);
+    init_pair(2, COLOR_RED, background);
+
+    window.attrset(COLOR_PAIR(1));
+    window.mvaddstr(1, 2, "Text on the terminal's default background");
+    window.attrset(COLOR_PAIR(2));
+    if default_background {
+        window.mvaddstr(2, 2, "A transparent terminal stays transparent behind it");
+    } else {
+        window.mvaddstr(2, 2, "This terminal has no default colors, so blue is used instead");
+    }
+    window.attrset(COLOR_PAIR(0));
+    window.mvaddstr(4, 2, "Press any key to exit");
+    window.refresh();
+    window.getch();
+    endwin();
+}
@@ -15,7 +15,7 @@ fn main() {
             }
             Some(Input::KeyDC) => break,
             Some(input) => {
-                window.addstr(&format!("{:?}", input));
+                window.addstr(format!("{:?}", input));
             }
             None => (),
         }
@@ -0,0 +1,44 @@
+extern crate pancurses;
+
+use pancurses::{
+    curs_set, endwin, getmouse, initscr, mousemask, newpad, noecho, Input, MouseEvent,
+    DEFAULT_MOUSE_EVENTS,
+};
+
+const PAD_LINES: i32 = 500;
+const WHEEL_STEP: i32 = 3;
+
+fn main() {
+    let window = initscr();
+    window.keypad(true);
+    noecho();
+    curs_set(0);
+    mousemask(DEFAULT_MOUSE_EVENTS, None);
+
+    let pad = newpad(PAD_LINES, window.get_max_x());
+    for line in 0..PAD_LINES {
+        pad.mvaddstr(line, 0, format!("Line {} of {}", line + 1, PAD_LINES));
+    }
+
+    let viewport_lines = window.get_max_y().min(20);
+    let max_top = PAD_LINES - viewport_lines;
+    let mut top = 0;
+    window.refresh();
+    loop {
+        pad.prefresh(top, 0, 0, 0, viewport_lines - 1, window.get_max_x() - 1);
+        match window.getch() {
+            Some(Input::KeyMouse) => {
+                if let Ok(event) = getmouse().map(MouseEvent::from) {
+                    if event.scroll_up {
+                        top = (top - WHEEL_STEP).max(0);
+                    } else if event.scroll_down {
+                        top = (top + WHEEL_STEP).min(max_top);
+                    }
+                }
+            }
+            Some(Input::Character('q')) => break,
+            _ => (),
+        }
+    }
+    endwin();
+}
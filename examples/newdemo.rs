@@ -147,7 +147,7 @@ fn main() {
             for i in (0..w + msg_len).rev() {
                 visbuf.clear();
                 for visbuf_i in 0..visbuf.capacity() {
-                    let i = i - msg_len as i32;
+                    let i = i - msg_len;
                     let char_index = visbuf_i as i32 - i;
                     let ch = if char_index >= 0 && char_index < message.len() as i32 {
                         let char_index = char_index as usize;
@@ -102,14 +102,14 @@ fn main() {
                 window.mv(12, 40);
                 text_in_a_box("Prev Ucode pg", &window);
             }
-            window.mvprintw(13, 40, &format!("U+{:04X} ", unicode_offset));
+            window.mvprintw(13, 40, format!("U+{:04X} ", unicode_offset));
 
             for i in 0..128 {
                 // Show extended characters
                 window.mvaddstr(
                     5 + i % 16,
                     (i / 16) * 5,
-                    &format!("{:02X} ", i + unicode_offset),
+                    format!("{:02X} ", i + unicode_offset),
                 );
                 if i + unicode_offset > ' ' as i32 {
                     window.addch((i + unicode_offset) as chtype);
@@ -203,14 +203,14 @@ fn main() {
                 set_blink(blink_state);
             }
             Some(x) if x != Input::KeyMouse => {
-                window.mvaddstr(0, COL1, &format!("Key {:?} hit          ", x));
+                window.mvaddstr(0, COL1, format!("Key {:?} hit          ", x));
             }
             Some(Input::KeyMouse) => {
                 if let Ok(mouse_event) = getmouse() {
                     window.mvaddstr(
                         0,
                         COL1,
-                        &format!(
+                        format!(
                             "Mouse at {} x {}: {}",
                             mouse_event.x,
                             mouse_event.y,
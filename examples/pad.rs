@@ -0,0 +1,31 @@
+extern crate pancurses;
+
+use pancurses::{curs_set, endwin, initscr, newpad, noecho, Input};
+
+const PAD_LINES: i32 = 500;
+
+fn main() {
+    let window = initscr();
+    window.keypad(true);
+    noecho();
+    curs_set(0);
+
+    let pad = newpad(PAD_LINES, window.get_max_x());
+    for line in 0..PAD_LINES {
+        pad.mvaddstr(line, 0, format!("Line {} of {}", line + 1, PAD_LINES));
+    }
+
+    let viewport_lines = window.get_max_y().min(20);
+    let mut top = 0;
+    window.refresh();
+    loop {
+        pad.prefresh(top, 0, 0, 0, viewport_lines - 1, window.get_max_x() - 1);
+        match window.getch() {
+            Some(Input::KeyUp) if top > 0 => top -= 1,
+            Some(Input::KeyDown) if top < PAD_LINES - viewport_lines => top += 1,
+            Some(Input::Character('q')) => break,
+            _ => (),
+        }
+    }
+    endwin();
+}
@@ -0,0 +1,34 @@
+extern crate pancurses;
+
+use pancurses::{endwin, initscr, noecho, suspend_tui, Input};
+use std::env;
+use std::process::Command;
+
+fn main() {
+    let window = initscr();
+    window.keypad(true);
+    noecho();
+
+    let editor = env::var("EDITOR").unwrap_or_else(|_| "vi".to_string());
+    let mut message = String::from("Press 'e' to edit a file, or 'q' to quit");
+    loop {
+        window.clear();
+        window.mvaddstr(1, 2, &message);
+        window.refresh();
+        match window.getch() {
+            Some(Input::Character('e')) => {
+                let path = env::temp_dir().join("pancurses_shell_out.txt");
+                // The editor gets the terminal in its normal state, and curses is back when it
+                // exits
+                let status = suspend_tui(|| Command::new(&editor).arg(&path).status());
+                message = match status {
+                    Ok(status) => format!("{} exited with {}", editor, status),
+                    Err(error) => format!("Couldn't run {}: {}", editor, error),
+                };
+            }
+            Some(Input::Character('q')) => break,
+            _ => (),
+        }
+    }
+    endwin();
+}
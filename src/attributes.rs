@@ -1,26 +1,82 @@
-use std::ops::{BitOr, BitXor};
-use super::{chtype, A_ALTCHARSET, A_BLINK, A_BOLD, A_CHARTEXT, A_DIM, A_INVIS, A_LEFTLINE};
+use std::fmt;
+use std::iter::FromIterator;
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, Not, Sub};
+use super::{chtype, PAIR_NUMBER, A_ALTCHARSET, A_BLINK, A_BOLD, A_CHARTEXT, A_COLOR, A_DIM, A_INVIS, A_LEFTLINE};
 use super::{A_ITALIC, A_OVERLINE, A_REVERSE, A_RIGHTLINE, A_STRIKEOUT, A_UNDERLINE};
+use super::{A_HORIZONTAL, A_LOW, A_PROTECT, A_STANDOUT, A_TOP, A_VERTICAL};
 use super::colorpair::ColorPair;
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(rename_all = "snake_case"))]
 pub enum Attribute {
     AlternativeCharSet,
     Bold,
     Blink,
     CharText,
     Dim,
+    Horizontal,
     Leftline,
     Invisible,
     Italic,
+    Low,
     Normal,
     Overline,
+    Protect,
     Reverse,
     Rightline,
+    Standout,
     Strikeout,
+    Top,
     Underline,
+    Vertical,
 }
 
+/// Every attribute that can be set on its own, in the order they're yielded by `Attributes::iter()`
+const FLAG_ATTRIBUTES: [Attribute; 19] = [
+    Attribute::AlternativeCharSet,
+    Attribute::Bold,
+    Attribute::Blink,
+    Attribute::CharText,
+    Attribute::Dim,
+    Attribute::Horizontal,
+    Attribute::Leftline,
+    Attribute::Invisible,
+    Attribute::Italic,
+    Attribute::Low,
+    Attribute::Overline,
+    Attribute::Protect,
+    Attribute::Reverse,
+    Attribute::Rightline,
+    Attribute::Standout,
+    Attribute::Strikeout,
+    Attribute::Top,
+    Attribute::Underline,
+    Attribute::Vertical,
+];
+
+/// The bits of every attribute that is a flag, i.e. everything except the character and color bits
+const KNOWN_ATTRIBUTES: chtype = A_ALTCHARSET
+    | A_BLINK
+    | A_BOLD
+    | A_DIM
+    | A_HORIZONTAL
+    | A_INVIS
+    | A_ITALIC
+    | A_LEFTLINE
+    | A_LOW
+    | A_OVERLINE
+    | A_PROTECT
+    | A_REVERSE
+    | A_RIGHTLINE
+    | A_STANDOUT
+    | A_STRIKEOUT
+    | A_TOP
+    | A_UNDERLINE
+    | A_VERTICAL;
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct Attributes {
     raw: chtype,
@@ -33,20 +89,56 @@ macro_rules! attribute_setter {
             if enabled {
                 self.raw |= $attr;
             } else {
-                self.raw ^= $attr;
+                self.raw &= !$attr;
             }
         }
     };
 }
 
 impl Attributes {
-    pub fn new() -> Attributes {
+    /// Attributes with nothing set. This is a `const fn`, as are the single attribute constants
+    /// below, so styles can be declared at compile time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pancurses::Attributes;
+    ///
+    /// const STYLES: [Attributes; 3] = [Attributes::new(), Attributes::BOLD, Attributes::REVERSE];
+    /// assert!(STYLES[0].is_normal());
+    /// assert!(STYLES[1].is_bold());
+    /// assert!(STYLES[2].is_reverse());
+    /// ```
+    pub const fn new() -> Attributes {
+        Attributes::from_raw(0)
+    }
+
+    const fn from_raw(raw: chtype) -> Attributes {
         Attributes {
-            raw: 0,
+            raw,
             color_pair: ColorPair(0),
         }
     }
 
+    pub const ALTERNATIVE_CHAR_SET: Attributes = Attributes::from_raw(A_ALTCHARSET);
+    pub const BOLD: Attributes = Attributes::from_raw(A_BOLD);
+    pub const BLINK: Attributes = Attributes::from_raw(A_BLINK);
+    pub const DIM: Attributes = Attributes::from_raw(A_DIM);
+    pub const HORIZONTAL: Attributes = Attributes::from_raw(A_HORIZONTAL);
+    pub const LEFTLINE: Attributes = Attributes::from_raw(A_LEFTLINE);
+    pub const INVISIBLE: Attributes = Attributes::from_raw(A_INVIS);
+    pub const ITALIC: Attributes = Attributes::from_raw(A_ITALIC);
+    pub const LOW: Attributes = Attributes::from_raw(A_LOW);
+    pub const OVERLINE: Attributes = Attributes::from_raw(A_OVERLINE);
+    pub const PROTECT: Attributes = Attributes::from_raw(A_PROTECT);
+    pub const REVERSE: Attributes = Attributes::from_raw(A_REVERSE);
+    pub const RIGHTLINE: Attributes = Attributes::from_raw(A_RIGHTLINE);
+    pub const STANDOUT: Attributes = Attributes::from_raw(A_STANDOUT);
+    pub const STRIKEOUT: Attributes = Attributes::from_raw(A_STRIKEOUT);
+    pub const TOP: Attributes = Attributes::from_raw(A_TOP);
+    pub const UNDERLINE: Attributes = Attributes::from_raw(A_UNDERLINE);
+    pub const VERTICAL: Attributes = Attributes::from_raw(A_VERTICAL);
+
     pub fn is_alternative_char_set(&self) -> bool {
         (self.raw & A_ALTCHARSET) > 0
     }
@@ -72,6 +164,12 @@ impl Attributes {
     }
     attribute_setter!(set_dim, A_DIM);
 
+    #[cfg_attr(windows, allow(clippy::bad_bit_mask))]
+    pub fn is_horizontal(&self) -> bool {
+        (self.raw & A_HORIZONTAL) > 0
+    }
+    attribute_setter!(set_horizontal, A_HORIZONTAL);
+
     #[cfg_attr(unix, allow(clippy::bad_bit_mask))]
     pub fn is_leftline(&self) -> bool {
         (self.raw & A_LEFTLINE) > 0
@@ -83,11 +181,18 @@ impl Attributes {
     }
     attribute_setter!(set_invisible, A_INVIS);
 
+    #[cfg_attr(unix, allow(clippy::bad_bit_mask))]
     pub fn is_italic(&self) -> bool {
         (self.raw & A_ITALIC) > 0
     }
     attribute_setter!(set_italic, A_ITALIC);
 
+    #[cfg_attr(windows, allow(clippy::bad_bit_mask))]
+    pub fn is_low(&self) -> bool {
+        (self.raw & A_LOW) > 0
+    }
+    attribute_setter!(set_low, A_LOW);
+
     pub fn is_normal(&self) -> bool {
         self.raw == 0
     }
@@ -101,6 +206,12 @@ impl Attributes {
     }
     attribute_setter!(set_overline, A_OVERLINE);
 
+    // On PDCurses protect is a combination of underline, leftline and rightline
+    pub fn is_protect(&self) -> bool {
+        (self.raw & A_PROTECT) == A_PROTECT
+    }
+    attribute_setter!(set_protect, A_PROTECT);
+
     pub fn is_reverse(&self) -> bool {
         (self.raw & A_REVERSE) > 0
     }
@@ -112,22 +223,217 @@ impl Attributes {
     }
     attribute_setter!(set_rightline, A_RIGHTLINE);
 
+    // On PDCurses standout is a combination of reverse and bold
+    pub fn is_standout(&self) -> bool {
+        (self.raw & A_STANDOUT) == A_STANDOUT
+    }
+    attribute_setter!(set_standout, A_STANDOUT);
+
     #[cfg_attr(unix, allow(clippy::bad_bit_mask))]
     pub fn is_strikeout(&self) -> bool {
         (self.raw & A_STRIKEOUT) > 0
     }
     attribute_setter!(set_strikeout, A_STRIKEOUT);
 
+    #[cfg_attr(windows, allow(clippy::bad_bit_mask))]
+    pub fn is_top(&self) -> bool {
+        (self.raw & A_TOP) > 0
+    }
+    attribute_setter!(set_top, A_TOP);
+
     pub fn is_underline(&self) -> bool {
         (self.raw & A_UNDERLINE) > 0
     }
     attribute_setter!(set_underline, A_UNDERLINE);
 
+    #[cfg_attr(windows, allow(clippy::bad_bit_mask))]
+    pub fn is_vertical(&self) -> bool {
+        (self.raw & A_VERTICAL) > 0
+    }
+    attribute_setter!(set_vertical, A_VERTICAL);
+
+    /// Checks if the given attribute is set. `Attribute::Normal` is only contained when no
+    /// attribute or color pair is set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pancurses::{Attribute, Attributes};
+    ///
+    /// let attributes = Attribute::Bold | Attribute::Underline;
+    /// assert!(attributes.contains(Attribute::Bold));
+    /// assert!(!attributes.contains(Attribute::Reverse));
+    /// assert!(!attributes.contains(Attribute::Normal));
+    /// assert!(Attributes::new().contains(Attribute::Normal));
+    /// ```
+    pub fn contains(&self, attribute: Attribute) -> bool {
+        match attribute {
+            Attribute::AlternativeCharSet => self.is_alternative_char_set(),
+            Attribute::Bold => self.is_bold(),
+            Attribute::Blink => self.is_blink(),
+            Attribute::CharText => self.is_char_text(),
+            Attribute::Dim => self.is_dim(),
+            Attribute::Horizontal => self.is_horizontal(),
+            Attribute::Leftline => self.is_leftline(),
+            Attribute::Invisible => self.is_invisible(),
+            Attribute::Italic => self.is_italic(),
+            Attribute::Low => self.is_low(),
+            Attribute::Normal => self.is_normal(),
+            Attribute::Overline => self.is_overline(),
+            Attribute::Protect => self.is_protect(),
+            Attribute::Reverse => self.is_reverse(),
+            Attribute::Rightline => self.is_rightline(),
+            Attribute::Standout => self.is_standout(),
+            Attribute::Strikeout => self.is_strikeout(),
+            Attribute::Top => self.is_top(),
+            Attribute::Underline => self.is_underline(),
+            Attribute::Vertical => self.is_vertical(),
+        }
+    }
+
+    /// Returns an iterator over the attributes that are set. `Attribute::Normal` is never
+    /// yielded, and the color pair is available through `color_pair()`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pancurses::{Attribute, Attributes};
+    ///
+    /// let attributes = Attribute::Underline | Attribute::Bold;
+    /// let set: Vec<Attribute> = attributes.iter().collect();
+    /// assert_eq!(set, vec![Attribute::Bold, Attribute::Underline]);
+    /// assert_eq!(Attributes::new().iter().count(), 0);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = Attribute> {
+        let attributes = *self;
+        FLAG_ATTRIBUTES
+            .iter()
+            .cloned()
+            .filter(move |attribute| attributes.contains(*attribute))
+    }
+
+    /// Returns the attributes set in either `self` or `other`. The color pair of `self` is kept,
+    /// unless it's 0 in which case the color pair of `other` is used. Same as `self | other`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pancurses::{Attribute, Attributes};
+    /// use pancurses::colorpair::ColorPair;
+    ///
+    /// let attributes = Attributes::from(Attribute::Bold);
+    /// let other = Attribute::Underline | ColorPair(2);
+    /// let union = attributes.union(other);
+    /// assert!(union.is_bold());
+    /// assert!(union.is_underline());
+    /// assert_eq!(union.color_pair(), ColorPair(2));
+    /// assert_eq!((ColorPair(1) | Attribute::Bold).union(other).color_pair(), ColorPair(1));
+    /// ```
+    pub fn union(self, other: Attributes) -> Attributes {
+        let mut attributes = Attributes {
+            raw: (self.raw | other.raw) & !A_COLOR,
+            color_pair: ColorPair(0),
+        };
+        if self.color_pair.0 != 0 {
+            attributes.set_color_pair(self.color_pair);
+        } else {
+            attributes.set_color_pair(other.color_pair);
+        }
+        attributes
+    }
+
+    /// Returns the attributes set in both `self` and `other`. The color pair is kept only if both
+    /// have the same one. Same as `self & other`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pancurses::{Attribute, Attributes};
+    /// use pancurses::colorpair::ColorPair;
+    ///
+    /// let attributes = Attribute::Bold | Attribute::Reverse | ColorPair(1);
+    /// let intersection = attributes.intersection(Attribute::Bold | ColorPair(2));
+    /// assert_eq!(intersection, Attributes::from(Attribute::Bold));
+    /// ```
+    pub fn intersection(self, other: Attributes) -> Attributes {
+        self & other
+    }
+
+    /// Returns the attributes set in `self` but not in `other`. The color pair is removed only if
+    /// both have the same one. Same as `self - other`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pancurses::{Attribute, Attributes};
+    /// use pancurses::colorpair::ColorPair;
+    ///
+    /// let attributes = Attribute::Bold | Attribute::Reverse | ColorPair(1);
+    /// let difference = attributes.difference(Attribute::Bold | ColorPair(2));
+    /// assert_eq!(difference, Attribute::Reverse | ColorPair(1));
+    /// ```
+    pub fn difference(self, other: Attributes) -> Attributes {
+        self - other
+    }
+
+    /// Checks if no attributes and no color pair are set.
+    pub fn is_empty(&self) -> bool {
+        self.raw == 0 && self.color_pair.0 == 0
+    }
+
+    /// Checks if every attribute set in `self` is also set in `other`, and that `self` either has
+    /// no color pair or the same one as `other`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pancurses::{Attribute, Attributes};
+    /// use pancurses::colorpair::ColorPair;
+    ///
+    /// let attributes = Attributes::from(Attribute::Bold);
+    /// assert!(attributes.is_subset(&(Attribute::Bold | Attribute::Reverse)));
+    /// assert!(attributes.is_subset(&(Attribute::Bold | ColorPair(2))));
+    /// assert!(!(attributes | ColorPair(1)).is_subset(&(Attribute::Bold | ColorPair(2))));
+    /// assert!(!attributes.is_subset(&Attributes::from(Attribute::Reverse)));
+    /// ```
+    pub fn is_subset(&self, other: &Attributes) -> bool {
+        (self.raw & !A_COLOR & !other.raw) == 0
+            && (self.color_pair.0 == 0 || self.color_pair == other.color_pair)
+    }
+
+    /// Turns the given attribute on if it's off and off if it's on. Toggling `Attribute::Normal`
+    /// does nothing. Same as `attributes = attributes ^ attribute`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pancurses::{Attribute, Attributes};
+    ///
+    /// let mut attributes = Attributes::from(Attribute::Underline);
+    /// attributes.toggle(Attribute::Bold);
+    /// assert!(attributes.is_bold());
+    /// attributes.toggle(Attribute::Bold);
+    /// assert_eq!(attributes, Attributes::from(Attribute::Underline));
+    /// ```
+    pub fn toggle(&mut self, attribute: Attribute) {
+        *self = *self ^ attribute;
+    }
+
+    /// Sets the given color pair, or clears the color pair back to 0 if it's already set.
+    pub fn toggle_color_pair(&mut self, color_pair: ColorPair) {
+        if self.color_pair == color_pair {
+            self.set_color_pair(ColorPair(0));
+        } else {
+            self.set_color_pair(color_pair);
+        }
+    }
+
     pub fn color_pair(&self) -> ColorPair {
         self.color_pair
     }
     pub fn set_color_pair(&mut self, color_pair: ColorPair) {
         let color_chtype: chtype = color_pair.into();
+        self.raw &= !A_COLOR;
         self.raw |= color_chtype;
         self.color_pair = color_pair;
     }
@@ -155,21 +461,27 @@ impl BitOr<Attribute> for Attributes {
             Attribute::Blink => self.set_blink(true),
             Attribute::CharText => self.set_char_text(true),
             Attribute::Dim => self.set_dim(true),
+            Attribute::Horizontal => self.set_horizontal(true),
             Attribute::Leftline => self.set_leftline(true),
             Attribute::Invisible => self.set_invisible(true),
             Attribute::Italic => self.set_italic(true),
+            Attribute::Low => self.set_low(true),
             Attribute::Normal => self.set_normal(),
             Attribute::Overline => self.set_overline(true),
+            Attribute::Protect => self.set_protect(true),
             Attribute::Reverse => self.set_reverse(true),
             Attribute::Rightline => self.set_rightline(true),
+            Attribute::Standout => self.set_standout(true),
             Attribute::Strikeout => self.set_strikeout(true),
+            Attribute::Top => self.set_top(true),
             Attribute::Underline => self.set_underline(true),
+            Attribute::Vertical => self.set_vertical(true),
         }
         self
     }
 }
 
-/// Implement the ^ operator for disabling an Attribute from Attributes
+/// Implement the ^ operator for toggling an Attribute in Attributes
 ///
 /// # Example
 ///
@@ -180,31 +492,42 @@ impl BitOr<Attribute> for Attributes {
 /// assert!(attributes.is_bold());
 /// attributes = attributes ^ Attribute::Bold;
 /// assert!(!attributes.is_bold());
+/// attributes = attributes ^ Attribute::Underline;
+/// assert!(attributes.is_underline());
 /// ```
 impl BitXor<Attribute> for Attributes {
     type Output = Attributes;
 
     fn bitxor(mut self, rhs: Attribute) -> Attributes {
-        match rhs {
-            Attribute::AlternativeCharSet => self.set_alternative_char_set(false),
-            Attribute::Bold => self.set_bold(false),
-            Attribute::Blink => self.set_blink(false),
-            Attribute::CharText => self.set_char_text(false),
-            Attribute::Dim => self.set_dim(false),
-            Attribute::Leftline => self.set_leftline(false),
-            Attribute::Invisible => self.set_invisible(false),
-            Attribute::Italic => self.set_italic(false),
-            Attribute::Normal => (),
-            Attribute::Overline => self.set_overline(false),
-            Attribute::Reverse => self.set_reverse(false),
-            Attribute::Rightline => self.set_rightline(false),
-            Attribute::Strikeout => self.set_strikeout(false),
-            Attribute::Underline => self.set_underline(false),
-        }
+        self.raw ^= chtype::from(rhs);
         self
     }
 }
 
+/// Implement the & operator for masking Attributes with an Attribute. The result contains only
+/// the given attribute, if it was set, and no color pair.
+///
+/// # Example
+///
+/// ```
+/// use pancurses::{Attribute, Attributes};
+/// use pancurses::colorpair::ColorPair;
+///
+/// let attributes = Attribute::Bold | Attribute::Reverse | ColorPair(2);
+/// assert_eq!(attributes & Attribute::Bold, Attributes::from(Attribute::Bold));
+/// assert_eq!(attributes & Attribute::Underline, Attributes::new());
+/// ```
+impl BitAnd<Attribute> for Attributes {
+    type Output = Attributes;
+
+    fn bitand(self, rhs: Attribute) -> Attributes {
+        Attributes {
+            raw: self.raw & chtype::from(rhs),
+            color_pair: ColorPair(0),
+        }
+    }
+}
+
 /// Implement the | operator for adding Attributes to Attributes
 ///
 /// # Example
@@ -223,10 +546,7 @@ impl BitOr for Attributes {
     type Output = Attributes;
 
     fn bitor(self, rhs: Attributes) -> Attributes {
-        Attributes {
-            raw: self.raw | rhs.raw,
-            color_pair: ColorPair(self.color_pair.0 | rhs.color_pair.0),
-        }
+        self.union(rhs)
     }
 }
 
@@ -255,6 +575,195 @@ impl BitXor for Attributes {
     }
 }
 
+/// Implement the & operator for the intersection of two Attributes. The color pair is kept only
+/// if both sides have the same one.
+///
+/// # Example
+///
+/// ```
+/// use pancurses::{Attribute, Attributes};
+/// use pancurses::colorpair::ColorPair;
+///
+/// let attributes = Attribute::Blink | Attribute::Bold | ColorPair(1);
+/// let other = Attribute::Reverse | Attribute::Bold | ColorPair(1);
+/// let both = attributes & other;
+/// assert!(both.is_bold());
+/// assert!(!both.is_blink());
+/// assert!(!both.is_reverse());
+/// assert_eq!(both.color_pair(), ColorPair(1));
+/// assert_eq!((attributes & (other | ColorPair(2))).color_pair(), ColorPair(0));
+/// ```
+impl BitAnd for Attributes {
+    type Output = Attributes;
+
+    fn bitand(self, rhs: Attributes) -> Attributes {
+        let mut attributes = Attributes {
+            raw: self.raw & rhs.raw & !A_COLOR,
+            color_pair: ColorPair(0),
+        };
+        if self.color_pair == rhs.color_pair {
+            attributes.set_color_pair(self.color_pair);
+        }
+        attributes
+    }
+}
+
+/// Implement the ! operator for inverting Attributes. Only the bits of the known attributes are
+/// inverted, excluding `CharText`, and the color pair is cleared.
+///
+/// # Example
+///
+/// ```
+/// use pancurses::{Attribute, Attributes};
+/// use pancurses::colorpair::ColorPair;
+///
+/// let attributes = !(Attribute::Bold | ColorPair(3));
+/// assert!(!attributes.is_bold());
+/// assert!(attributes.is_underline());
+/// assert!(!attributes.is_char_text());
+/// assert_eq!(attributes.color_pair(), ColorPair(0));
+/// assert_eq!(!attributes, Attributes::from(Attribute::Bold));
+/// ```
+impl Not for Attributes {
+    type Output = Attributes;
+
+    fn not(self) -> Attributes {
+        Attributes {
+            raw: !self.raw & KNOWN_ATTRIBUTES,
+            color_pair: ColorPair(0),
+        }
+    }
+}
+
+/// Implement the |= operator for adding an Attribute to Attributes
+///
+/// # Example
+///
+/// ```
+/// use pancurses::{Attribute, Attributes};
+///
+/// let mut attributes = Attributes::new();
+/// attributes |= Attribute::Bold;
+/// attributes |= Attribute::Reverse;
+/// assert!(attributes.is_bold());
+/// assert!(attributes.is_reverse());
+/// ```
+impl BitOrAssign<Attribute> for Attributes {
+    fn bitor_assign(&mut self, rhs: Attribute) {
+        *self = *self | rhs;
+    }
+}
+
+/// Implement the |= operator for adding Attributes to Attributes
+///
+/// # Example
+///
+/// ```
+/// use pancurses::{Attribute, Attributes};
+/// use pancurses::colorpair::ColorPair;
+///
+/// let mut attributes = Attributes::from(Attribute::Bold);
+/// attributes |= Attribute::Underline | ColorPair(2);
+/// assert!(attributes.is_bold());
+/// assert!(attributes.is_underline());
+/// assert_eq!(attributes.color_pair(), ColorPair(2));
+/// ```
+impl BitOrAssign for Attributes {
+    fn bitor_assign(&mut self, rhs: Attributes) {
+        *self = *self | rhs;
+    }
+}
+
+/// Implement the &= operator for masking Attributes with an Attribute
+///
+/// # Example
+///
+/// ```
+/// use pancurses::{Attribute, Attributes};
+///
+/// let mut attributes = Attribute::Bold | Attribute::Reverse;
+/// attributes &= Attribute::Bold;
+/// assert!(attributes.is_bold());
+/// assert!(!attributes.is_reverse());
+/// ```
+impl BitAndAssign<Attribute> for Attributes {
+    fn bitand_assign(&mut self, rhs: Attribute) {
+        *self = *self & rhs;
+    }
+}
+
+/// Implement the &= operator for the intersection of two Attributes
+///
+/// # Example
+///
+/// ```
+/// use pancurses::{Attribute, Attributes};
+/// use pancurses::colorpair::ColorPair;
+///
+/// let mut attributes = Attribute::Bold | Attribute::Reverse | ColorPair(1);
+/// attributes &= Attribute::Bold | Attribute::Underline | ColorPair(1);
+/// assert!(attributes.is_bold());
+/// assert!(!attributes.is_reverse());
+/// assert!(!attributes.is_underline());
+/// assert_eq!(attributes.color_pair(), ColorPair(1));
+/// ```
+impl BitAndAssign for Attributes {
+    fn bitand_assign(&mut self, rhs: Attributes) {
+        *self = *self & rhs;
+    }
+}
+
+/// Implement the - operator for removing an Attribute from Attributes
+///
+/// # Example
+///
+/// ```
+/// use pancurses::{Attribute, Attributes};
+///
+/// let attributes = Attribute::Bold | Attribute::Underline;
+/// assert_eq!(attributes - Attribute::Underline, Attributes::from(Attribute::Bold));
+/// assert_eq!(attributes - Attribute::Reverse, attributes);
+/// ```
+impl Sub<Attribute> for Attributes {
+    type Output = Attributes;
+
+    fn sub(mut self, rhs: Attribute) -> Attributes {
+        self.raw &= !chtype::from(rhs);
+        self
+    }
+}
+
+/// Implement the - operator for removing Attributes from Attributes. The color pair is removed
+/// only if both sides have the same one.
+///
+/// # Example
+///
+/// ```
+/// use pancurses::{Attribute, Attributes};
+/// use pancurses::colorpair::ColorPair;
+///
+/// let attributes = Attribute::Bold | Attribute::Underline | ColorPair(2);
+/// let remaining = attributes - (Attribute::Underline | ColorPair(2));
+/// assert!(remaining.is_bold());
+/// assert!(!remaining.is_underline());
+/// assert_eq!(remaining.color_pair(), ColorPair(0));
+/// assert_eq!((attributes - ColorPair(1)).color_pair(), ColorPair(2));
+/// ```
+impl Sub for Attributes {
+    type Output = Attributes;
+
+    fn sub(self, rhs: Attributes) -> Attributes {
+        let mut attributes = Attributes {
+            raw: self.raw & !(rhs.raw & !A_COLOR),
+            color_pair: self.color_pair,
+        };
+        if self.color_pair == rhs.color_pair {
+            attributes.set_color_pair(ColorPair(0));
+        }
+        attributes
+    }
+}
+
 /// Implement the | operator for combining two 'Attribute's into Attributes
 ///
 /// # Example
@@ -290,6 +799,144 @@ impl Default for Attributes {
     }
 }
 
+/// Implement Display for Attribute
+///
+/// # Example
+///
+/// ```
+/// use pancurses::Attribute;
+/// assert_eq!(Attribute::Bold.to_string(), "bold");
+/// assert_eq!(Attribute::AlternativeCharSet.to_string(), "alternative_char_set");
+/// ```
+impl fmt::Display for Attribute {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match *self {
+            Attribute::AlternativeCharSet => "alternative_char_set",
+            Attribute::Bold => "bold",
+            Attribute::Blink => "blink",
+            Attribute::CharText => "char_text",
+            Attribute::Dim => "dim",
+            Attribute::Horizontal => "horizontal",
+            Attribute::Leftline => "leftline",
+            Attribute::Invisible => "invisible",
+            Attribute::Italic => "italic",
+            Attribute::Low => "low",
+            Attribute::Normal => "normal",
+            Attribute::Overline => "overline",
+            Attribute::Protect => "protect",
+            Attribute::Reverse => "reverse",
+            Attribute::Rightline => "rightline",
+            Attribute::Standout => "standout",
+            Attribute::Strikeout => "strikeout",
+            Attribute::Top => "top",
+            Attribute::Underline => "underline",
+            Attribute::Vertical => "vertical",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Implement Display for Attributes, joining the set attributes and the color pair with |
+///
+/// # Example
+///
+/// ```
+/// use pancurses::{Attribute, Attributes};
+/// use pancurses::colorpair::ColorPair;
+///
+/// let attributes = Attribute::Bold | Attribute::Reverse | ColorPair(3);
+/// assert_eq!(attributes.to_string(), "bold|reverse|color_pair(3)");
+/// assert_eq!(Attributes::new().to_string(), "normal");
+/// ```
+impl fmt::Display for Attributes {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut separator = "";
+        for attribute in self.iter() {
+            write!(f, "{}{}", separator, attribute)?;
+            separator = "|";
+        }
+        if self.color_pair.0 != 0 {
+            write!(f, "{}{}", separator, self.color_pair)?;
+            separator = "|";
+        }
+        if separator.is_empty() {
+            f.write_str("normal")?;
+        }
+        Ok(())
+    }
+}
+
+/// The platform independent form Attributes are serialized in, since the raw chtype differs
+/// between backends.
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+struct SerializedAttributes {
+    attributes: Vec<Attribute>,
+    #[serde(default)]
+    color_pair: Option<u8>,
+}
+
+/// Serializes Attributes as a list of attribute names and an optional color pair number
+#[cfg(feature = "serde")]
+impl Serialize for Attributes {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SerializedAttributes {
+            attributes: self.iter().collect(),
+            color_pair: match self.color_pair {
+                ColorPair(0) => None,
+                color_pair => Some(color_pair.0),
+            },
+        }
+        .serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Attributes {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Attributes, D::Error> {
+        let serialized = SerializedAttributes::deserialize(deserializer)?;
+        let attributes: Attributes = serialized.attributes.into_iter().collect();
+        Ok(attributes | ColorPair(serialized.color_pair.unwrap_or(0)))
+    }
+}
+
+/// Implement FromIterator for collecting Attributes, the same as combining them with |
+///
+/// # Example
+///
+/// ```
+/// use pancurses::{Attribute, Attributes};
+///
+/// let attributes: Attributes = vec![Attribute::Bold, Attribute::Underline].into_iter().collect();
+/// assert_eq!(attributes, Attribute::Bold | Attribute::Underline);
+/// ```
+impl FromIterator<Attribute> for Attributes {
+    fn from_iter<I: IntoIterator<Item = Attribute>>(iter: I) -> Attributes {
+        let mut attributes = Attributes::new();
+        attributes.extend(iter);
+        attributes
+    }
+}
+
+/// Implement Extend for adding Attributes, the same as combining them with |
+///
+/// # Example
+///
+/// ```
+/// use pancurses::{Attribute, Attributes};
+///
+/// let mut attributes = Attributes::from(Attribute::Bold);
+/// attributes.extend(vec![Attribute::Reverse, Attribute::Blink]);
+/// assert_eq!(attributes, Attribute::Bold | Attribute::Reverse | Attribute::Blink);
+/// ```
+impl Extend<Attribute> for Attributes {
+    fn extend<I: IntoIterator<Item = Attribute>>(&mut self, iter: I) {
+        for attribute in iter {
+            *self |= attribute;
+        }
+    }
+}
+
 impl From<Attribute> for Attributes {
     fn from(attribute: Attribute) -> Attributes {
         Attributes::new() | attribute
@@ -302,8 +949,202 @@ impl From<Attribute> for chtype {
     }
 }
 
+/// Implement From for extracting the Attributes of a chtype, such as one returned by
+/// `Window::mvinch()`. The character part is discarded.
+///
+/// # Example
+///
+/// ```
+/// use pancurses::{chtype, Attribute, Attributes};
+/// use pancurses::colorpair::ColorPair;
+///
+/// let ch = 'a' as chtype | chtype::from(Attribute::Bold | ColorPair(2));
+/// let attributes = Attributes::from(ch);
+/// assert!(attributes.is_bold());
+/// assert_eq!(attributes.color_pair(), ColorPair(2));
+/// ```
+impl From<chtype> for Attributes {
+    fn from(ch: chtype) -> Attributes {
+        Attributes {
+            raw: ch & !A_CHARTEXT,
+            color_pair: ColorPair(PAIR_NUMBER(ch) as u8),
+        }
+    }
+}
+
 impl From<Attributes> for chtype {
     fn from(attributes: Attributes) -> chtype {
         attributes.raw
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Setter = fn(&mut Attributes, bool);
+    type Getter = fn(&Attributes) -> bool;
+
+    #[test]
+    fn test_disabling_unset_attribute_keeps_it_unset() {
+        let setters: [(Setter, Getter); 19] = [
+            (Attributes::set_alternative_char_set, Attributes::is_alternative_char_set),
+            (Attributes::set_bold, Attributes::is_bold),
+            (Attributes::set_blink, Attributes::is_blink),
+            (Attributes::set_char_text, Attributes::is_char_text),
+            (Attributes::set_dim, Attributes::is_dim),
+            (Attributes::set_horizontal, Attributes::is_horizontal),
+            (Attributes::set_leftline, Attributes::is_leftline),
+            (Attributes::set_invisible, Attributes::is_invisible),
+            (Attributes::set_italic, Attributes::is_italic),
+            (Attributes::set_low, Attributes::is_low),
+            (Attributes::set_overline, Attributes::is_overline),
+            (Attributes::set_protect, Attributes::is_protect),
+            (Attributes::set_reverse, Attributes::is_reverse),
+            (Attributes::set_rightline, Attributes::is_rightline),
+            (Attributes::set_standout, Attributes::is_standout),
+            (Attributes::set_strikeout, Attributes::is_strikeout),
+            (Attributes::set_top, Attributes::is_top),
+            (Attributes::set_underline, Attributes::is_underline),
+            (Attributes::set_vertical, Attributes::is_vertical),
+        ];
+
+        for (set, is) in setters.iter() {
+            let mut attributes = Attributes::new();
+            set(&mut attributes, false);
+            assert!(!is(&attributes));
+            set(&mut attributes, false);
+            assert!(!is(&attributes));
+            assert_eq!(Attributes::new(), attributes);
+        }
+    }
+
+    #[test]
+    fn test_chtype_round_trip() {
+        let attributes = [
+            Attribute::AlternativeCharSet,
+            Attribute::Bold,
+            Attribute::Blink,
+            Attribute::Dim,
+            Attribute::Horizontal,
+            Attribute::Leftline,
+            Attribute::Invisible,
+            Attribute::Italic,
+            Attribute::Low,
+            Attribute::Normal,
+            Attribute::Overline,
+            Attribute::Protect,
+            Attribute::Reverse,
+            Attribute::Rightline,
+            Attribute::Standout,
+            Attribute::Strikeout,
+            Attribute::Top,
+            Attribute::Underline,
+            Attribute::Vertical,
+        ];
+
+        for attribute in attributes.iter() {
+            for pair in [0, 1, 7, 255].iter() {
+                let original = Attributes::from(*attribute) | ColorPair(*pair);
+                assert_eq!(original, Attributes::from(chtype::from(original)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_toggle_twice_restores_original() {
+        let original = Attribute::Bold | Attribute::Reverse | ColorPair(2);
+        for attribute in FLAG_ATTRIBUTES.iter().chain([Attribute::Normal].iter()) {
+            let mut attributes = original;
+            attributes.toggle(*attribute);
+            attributes.toggle(*attribute);
+            assert_eq!(original, attributes);
+        }
+
+        let without_pair = original - ColorPair(2);
+        for (original, color_pair) in [(original, 2), (without_pair, 2), (without_pair, 5)].iter() {
+            let mut attributes = *original;
+            attributes.toggle_color_pair(ColorPair(*color_pair));
+            attributes.toggle_color_pair(ColorPair(*color_pair));
+            assert_eq!(*original, attributes);
+        }
+    }
+
+    #[test]
+    fn test_toggle_color_pair() {
+        let mut attributes = Attribute::Bold | ColorPair(2);
+        attributes.toggle_color_pair(ColorPair(3));
+        assert_eq!(ColorPair(3), attributes.color_pair());
+        attributes.toggle_color_pair(ColorPair(3));
+        assert_eq!(Attributes::from(Attribute::Bold), attributes);
+    }
+
+    #[test]
+    fn test_iter_yields_set_attributes() {
+        let attributes = Attribute::Reverse | Attribute::Blink | ColorPair(2);
+        let set: Vec<Attribute> = attributes.iter().collect();
+        assert_eq!(vec![Attribute::Blink, Attribute::Reverse], set);
+
+        let attributes = Attributes::new() | Attribute::Bold | Attribute::Normal;
+        assert_eq!(0, attributes.iter().count());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_attributes() {
+        use serde_test::{assert_tokens, Token};
+
+        assert_tokens(
+            &(Attribute::Bold | Attribute::Underline | ColorPair(3)),
+            &[
+                Token::Struct {
+                    name: "SerializedAttributes",
+                    len: 2,
+                },
+                Token::Str("attributes"),
+                Token::Seq { len: Some(2) },
+                Token::UnitVariant {
+                    name: "Attribute",
+                    variant: "bold",
+                },
+                Token::UnitVariant {
+                    name: "Attribute",
+                    variant: "underline",
+                },
+                Token::SeqEnd,
+                Token::Str("color_pair"),
+                Token::Some,
+                Token::U8(3),
+                Token::StructEnd,
+            ],
+        );
+
+        assert_tokens(
+            &Attributes::new(),
+            &[
+                Token::Struct {
+                    name: "SerializedAttributes",
+                    len: 2,
+                },
+                Token::Str("attributes"),
+                Token::Seq { len: Some(0) },
+                Token::SeqEnd,
+                Token::Str("color_pair"),
+                Token::None,
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_set_color_pair_replaces_previous_pair() {
+        let mut attributes = Attributes::new() | Attribute::Bold;
+        attributes.set_color_pair(ColorPair(3));
+        attributes.set_color_pair(ColorPair(4));
+        assert_eq!(ColorPair(4), attributes.color_pair());
+        assert_eq!(
+            chtype::from(Attribute::Bold) | chtype::from(ColorPair(4)),
+            chtype::from(attributes)
+        );
+    }
+}
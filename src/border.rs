@@ -0,0 +1,112 @@
+use super::chtype;
+use super::{ACS_HLINE, ACS_LLCORNER, ACS_LRCORNER, ACS_ULCORNER, ACS_URCORNER, ACS_VLINE};
+
+/// The characters used to draw each edge and corner of a border with `Window::draw_border()`.
+///
+/// The default uses the terminal's line-drawing characters, so like the `ACS_*` functions it's
+/// only meaningful after `initscr()` has been called.
+///
+/// # Example
+///
+/// ```
+/// use pancurses::{initscr, endwin, newwin, BorderChars, A_BOLD, A_CHARTEXT};
+///
+/// let window = initscr();
+/// let frame = newwin(3, 6, 0, 0);
+/// let chars = BorderChars {
+///     top_left: '*' as u32,
+///     ..BorderChars::default()
+/// };
+/// frame.draw_border(&chars.attributes(A_BOLD));
+/// assert_eq!('*' as u32 | A_BOLD, frame.mvinch(0, 0));
+/// assert_eq!(BorderChars::default().top, frame.mvinch(0, 1) & !A_BOLD);
+/// endwin();
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct BorderChars {
+    pub left: chtype,
+    pub right: chtype,
+    pub top: chtype,
+    pub bottom: chtype,
+    pub top_left: chtype,
+    pub top_right: chtype,
+    pub bottom_left: chtype,
+    pub bottom_right: chtype,
+}
+
+impl BorderChars {
+    /// A border made of plain ASCII characters, for terminals without line-drawing support.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pancurses::{initscr, endwin, newwin, BorderChars};
+    ///
+    /// let window = initscr();
+    /// let frame = newwin(3, 6, 0, 0);
+    /// frame.draw_border(&BorderChars::ascii());
+    /// assert_eq!("+----+", frame.mvinstr(0, 0));
+    /// assert_eq!("|    |", frame.mvinstr(1, 0));
+    /// endwin();
+    /// ```
+    pub fn ascii() -> BorderChars {
+        BorderChars {
+            left: '|' as chtype,
+            right: '|' as chtype,
+            top: '-' as chtype,
+            bottom: '-' as chtype,
+            top_left: '+' as chtype,
+            top_right: '+' as chtype,
+            bottom_left: '+' as chtype,
+            bottom_right: '+' as chtype,
+        }
+    }
+
+    /// A border made of the Unicode double-line box drawing characters.
+    ///
+    /// Only available on PDCurses, as an ncurses chtype has no room for characters outside of
+    /// the 8 bit range.
+    #[cfg(windows)]
+    pub fn double() -> BorderChars {
+        BorderChars {
+            left: '║' as chtype,
+            right: '║' as chtype,
+            top: '═' as chtype,
+            bottom: '═' as chtype,
+            top_left: '╔' as chtype,
+            top_right: '╗' as chtype,
+            bottom_left: '╚' as chtype,
+            bottom_right: '╝' as chtype,
+        }
+    }
+
+    /// Returns a copy with the given attributes applied to every edge and corner character.
+    pub fn attributes<A: Into<chtype>>(self, attributes: A) -> BorderChars {
+        let attributes = attributes.into();
+        BorderChars {
+            left: self.left | attributes,
+            right: self.right | attributes,
+            top: self.top | attributes,
+            bottom: self.bottom | attributes,
+            top_left: self.top_left | attributes,
+            top_right: self.top_right | attributes,
+            bottom_left: self.bottom_left | attributes,
+            bottom_right: self.bottom_right | attributes,
+        }
+    }
+}
+
+impl Default for BorderChars {
+    fn default() -> BorderChars {
+        BorderChars {
+            left: ACS_VLINE(),
+            right: ACS_VLINE(),
+            top: ACS_HLINE(),
+            bottom: ACS_HLINE(),
+            top_left: ACS_ULCORNER(),
+            top_right: ACS_URCORNER(),
+            bottom_left: ACS_LLCORNER(),
+            bottom_right: ACS_LRCORNER(),
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use super::{COLORS, COLOR_BLACK, COLOR_BLUE, COLOR_CYAN, COLOR_GREEN, COLOR_MAGENTA};
+use super::{COLOR_RED, COLOR_WHITE, COLOR_YELLOW};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A color to use with `init_pair_colors()`, so that colors can't be mixed up with pair numbers.
+///
+/// The bright variants are colors 8 to 15 when `COLORS()` is at least 16, and the matching
+/// standard color otherwise, since that's all the terminal has. On such terminals it's up to the
+/// caller to draw with `A_BOLD` if the text should still stand out, as many terminals show bold
+/// text in the bright color. Because of this the conversion looks at `COLORS()`, so convert
+/// after `start_color()`.
+///
+/// # Example
+///
+/// ```
+/// use pancurses::{Color, COLOR_RED};
+///
+/// assert_eq!(COLOR_RED, i16::from(Color::Red));
+/// assert_eq!(-1, i16::from(Color::Default));
+/// assert_eq!(208, i16::from(Color::Number(208)));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(rename_all = "snake_case"))]
+pub enum Color {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    BrightBlack,
+    BrightRed,
+    BrightGreen,
+    BrightYellow,
+    BrightBlue,
+    BrightMagenta,
+    BrightCyan,
+    BrightWhite,
+    /// The terminal's default foreground or background, -1, which needs `use_default_colors()`
+    /// or `assume_default_colors()`.
+    Default,
+    /// A color by its number in the terminal's palette.
+    Number(i16),
+}
+
+impl From<Color> for i16 {
+    fn from(color: Color) -> i16 {
+        let standard = match color {
+            Color::Black | Color::BrightBlack => COLOR_BLACK,
+            Color::Red | Color::BrightRed => COLOR_RED,
+            Color::Green | Color::BrightGreen => COLOR_GREEN,
+            Color::Yellow | Color::BrightYellow => COLOR_YELLOW,
+            Color::Blue | Color::BrightBlue => COLOR_BLUE,
+            Color::Magenta | Color::BrightMagenta => COLOR_MAGENTA,
+            Color::Cyan | Color::BrightCyan => COLOR_CYAN,
+            Color::White | Color::BrightWhite => COLOR_WHITE,
+            Color::Default => return -1,
+            Color::Number(number) => return number,
+        };
+        let bright = matches!(
+            color,
+            Color::BrightBlack
+                | Color::BrightRed
+                | Color::BrightGreen
+                | Color::BrightYellow
+                | Color::BrightBlue
+                | Color::BrightMagenta
+                | Color::BrightCyan
+                | Color::BrightWhite
+        );
+        if bright && COLORS() >= 16 {
+            standard + 8
+        } else {
+            standard
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bright_colors_fall_back_without_16_colors() {
+        // Colors haven't been started, so COLORS() is 0
+        assert_eq!(COLOR_CYAN, i16::from(Color::BrightCyan));
+        assert_eq!(COLOR_BLACK, i16::from(Color::Black));
+        assert_eq!(-1, i16::from(Color::Default));
+        assert_eq!(12, i16::from(Color::Number(12)));
+    }
+}
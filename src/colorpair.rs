@@ -1,10 +1,222 @@
-use std::ops::BitOr;
-use super::{chtype, COLOR_PAIR};
+use std::convert::TryFrom;
+use std::fmt;
+use std::num::TryFromIntError;
+use std::ops::{BitOr, BitOrAssign, Sub};
+use super::{chtype, init_pair, COLOR_PAIR, COLOR_PAIRS, ERR};
 use crate::attributes::{Attribute, Attributes};
+use crate::error::ColorError;
 
+/// The number of a color pair, as opposed to the attribute bits that select it, which are given
+/// by `to_attr()`.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct ColorPair(pub u8);
 
+impl ColorPair {
+    /// Returns the attribute bits for this color pair, the same as `COLOR_PAIR(n)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pancurses::{chtype, COLOR_PAIR};
+    /// use pancurses::colorpair::ColorPair;
+    ///
+    /// assert_eq!(ColorPair(3).to_attr(), COLOR_PAIR(3));
+    /// ```
+    pub fn to_attr(self) -> chtype {
+        chtype::from(self)
+    }
+}
+
+/// Hands out color pair numbers for foreground and background combinations as they're first
+/// needed, so separate parts of a program don't have to agree on who owns which number.
+///
+/// Pairs are numbered from 1 up, leaving pair 0 alone, and initialized with init_pair() on
+/// first use. Asking for a combination again returns the pair it already has. Once the
+/// numbers run out, at `COLOR_PAIRS() - 1` or 255, whichever is lower,
+/// `ColorError::PairsExhausted` is returned. Since `new()` is a `const fn`, a registry can be
+/// shared as a `static Mutex<ColorPairs>` as well as owned.
+///
+/// Parts of the program that pick their own pair numbers should set them up through
+/// `ColorPairs::init_pair()` rather than the free function, so that the registry doesn't hand
+/// them out again. Asking it for a number it has already handed out for other colors fails with
+/// `ColorError::PairTaken`.
+///
+/// Call start_color() before using it, or every call fails with `ColorError::ColorsNotStarted`.
+/// A combination curses rejects, such as a color beyond `COLORS()`, is reported as
+/// `ColorError::Curses` and isn't recorded, so it doesn't use up a pair.
+///
+/// # Example
+///
+/// ```
+/// use pancurses::{endwin, has_colors, initscr, start_color, ColorError, ColorPair, ColorPairs};
+/// use pancurses::{COLORS, COLOR_BLACK, COLOR_PAIRS, COLOR_RED, COLOR_YELLOW, ERR};
+/// use std::sync::Mutex;
+///
+/// static PAIRS: Mutex<ColorPairs> = Mutex::new(ColorPairs::new());
+///
+/// initscr();
+/// let mut pairs = PAIRS.lock().unwrap();
+/// assert_eq!(Err(ColorError::ColorsNotStarted), pairs.get_or_init(COLOR_RED, COLOR_BLACK));
+/// if has_colors() {
+///     start_color();
+///     let warning = pairs.get_or_init(COLOR_YELLOW, COLOR_BLACK).unwrap();
+///     assert_eq!(warning, pairs.get_or_init(COLOR_YELLOW, COLOR_BLACK).unwrap());
+///     assert_ne!(warning, pairs.get_or_init(COLOR_RED, COLOR_BLACK).unwrap());
+///
+///     // A color the terminal doesn't have is rejected and doesn't take up a pair
+///     let rejected = pairs.get_or_init(COLORS() as i16, COLOR_BLACK);
+///     assert_eq!(Err(ColorError::Curses(ERR)), rejected);
+///     assert_eq!(2, pairs.len());
+///
+///     // A number the program picked itself is skipped, and can't be taken twice
+///     assert_eq!(Ok(ColorPair(3)), pairs.init_pair(3, COLOR_BLACK, COLOR_RED));
+///     assert_eq!(Ok(ColorPair(4)), pairs.get_or_init(COLOR_BLACK, COLOR_YELLOW));
+///     let taken = pairs.init_pair(1, COLOR_RED, COLOR_RED);
+///     assert_eq!(Err(ColorError::PairTaken { pair: 1 }), taken);
+///
+///     // Asking for more combinations than there are pairs fails instead of wrapping around
+///     let mut owned = ColorPairs::new();
+///     let colors = COLORS() as i16;
+///     let exhausted = (0..).find(|&n| owned.get_or_init(n % colors, n / colors).is_err());
+///     assert_eq!(Some((COLOR_PAIRS() - 1).min(255) as i16), exhausted);
+///     let n = exhausted.unwrap();
+///     assert_eq!(Err(ColorError::PairsExhausted), owned.get_or_init(n % colors, n / colors));
+/// }
+/// endwin();
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ColorPairs {
+    // The foreground and background of pair n are at index n - 1, or None if it isn't in use
+    pairs: Vec<Option<(i16, i16)>>,
+}
+
+impl ColorPairs {
+    /// Creates a registry with no pairs allocated yet.
+    pub const fn new() -> ColorPairs {
+        ColorPairs { pairs: Vec::new() }
+    }
+
+    /// Returns the pair for the combination, allocating and initializing the lowest free pair if
+    /// the combination hasn't been asked for yet.
+    pub fn get_or_init(
+        &mut self,
+        foreground: i16,
+        background: i16,
+    ) -> Result<ColorPair, ColorError> {
+        let colors = Some((foreground, background));
+        if let Some(index) = self.pairs.iter().position(|&pair| pair == colors) {
+            return Ok(ColorPair(index as u8 + 1));
+        }
+        if COLOR_PAIRS() <= 0 {
+            return Err(ColorError::ColorsNotStarted);
+        }
+        let index = self.pairs.iter().position(Option::is_none).unwrap_or(self.pairs.len());
+        let number = index + 1;
+        if number as i32 >= COLOR_PAIRS().min(256) {
+            return Err(ColorError::PairsExhausted);
+        }
+        if init_pair(number as i16, foreground, background) == ERR {
+            return Err(ColorError::Curses(ERR));
+        }
+        if index == self.pairs.len() {
+            self.pairs.push(colors);
+        } else {
+            self.pairs[index] = colors;
+        }
+        Ok(ColorPair(number as u8))
+    }
+
+    /// Initializes the given pair number with init_pair() and records it, so that
+    /// get_or_init() doesn't hand it out. Setting a pair up again with the same colors is fine,
+    /// but a pair the registry already has for other colors is left alone and
+    /// `ColorError::PairTaken` is returned.
+    pub fn init_pair(
+        &mut self,
+        pair: i16,
+        foreground: i16,
+        background: i16,
+    ) -> Result<ColorPair, ColorError> {
+        if COLOR_PAIRS() <= 0 {
+            return Err(ColorError::ColorsNotStarted);
+        }
+        let max = (COLOR_PAIRS() - 1).min(255);
+        if pair < 1 || i32::from(pair) > max {
+            return Err(ColorError::PairOutOfRange { max });
+        }
+        let index = pair as usize - 1;
+        let colors = (foreground, background);
+        if let Some(&Some(taken)) = self.pairs.get(index) {
+            if taken != colors {
+                return Err(ColorError::PairTaken { pair });
+            }
+        }
+        if init_pair(pair, foreground, background) == ERR {
+            return Err(ColorError::Curses(ERR));
+        }
+        if index >= self.pairs.len() {
+            self.pairs.resize(index + 1, None);
+        }
+        self.pairs[index] = Some(colors);
+        Ok(ColorPair(pair as u8))
+    }
+
+    /// Returns true if no pairs have been allocated.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of pairs allocated.
+    pub fn len(&self) -> usize {
+        self.pairs.iter().filter(|pair| pair.is_some()).count()
+    }
+}
+
+impl From<u8> for ColorPair {
+    fn from(n: u8) -> ColorPair {
+        ColorPair(n)
+    }
+}
+
+/// Converts the i16 pair numbers used by functions such as `init_pair()`. Fails for numbers that
+/// are negative or too large to fit.
+///
+/// # Example
+///
+/// ```
+/// use std::convert::TryFrom;
+/// use pancurses::colorpair::ColorPair;
+///
+/// assert_eq!(ColorPair::try_from(3i16), Ok(ColorPair(3)));
+/// assert!(ColorPair::try_from(-1i16).is_err());
+/// ```
+impl TryFrom<i16> for ColorPair {
+    type Error = TryFromIntError;
+
+    fn try_from(n: i16) -> Result<ColorPair, TryFromIntError> {
+        u8::try_from(n).map(ColorPair)
+    }
+}
+
+impl From<ColorPair> for i16 {
+    fn from(color_pair: ColorPair) -> i16 {
+        i16::from(color_pair.0)
+    }
+}
+
+/// Implement Display for ColorPair
+///
+/// # Example
+///
+/// ```
+/// use pancurses::colorpair::ColorPair;
+/// assert_eq!(ColorPair(3).to_string(), "color_pair(3)");
+/// ```
+impl fmt::Display for ColorPair {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "color_pair({})", self.0)
+    }
+}
+
 impl From<ColorPair> for chtype {
     fn from(color_pair: ColorPair) -> chtype {
         COLOR_PAIR(chtype::from(color_pair.0))
@@ -33,6 +245,49 @@ impl BitOr<ColorPair> for Attributes {
     }
 }
 
+/// Implement the |= operator for setting a color pair on an `Attributes` object
+///
+/// # Example
+///
+/// ```
+/// use pancurses::{Attribute, Attributes};
+/// use pancurses::colorpair::ColorPair;
+///
+/// let mut attributes = Attributes::from(Attribute::Bold);
+/// attributes |= ColorPair(3);
+/// assert!(attributes.is_bold());
+/// assert!(attributes.color_pair().0 == 3);
+/// ```
+impl BitOrAssign<ColorPair> for Attributes {
+    fn bitor_assign(&mut self, rhs: ColorPair) {
+        self.set_color_pair(rhs);
+    }
+}
+
+/// Implement the - operator for removing a color pair from an `Attributes` object. Nothing is
+/// removed if the `Attributes` have a different color pair.
+///
+/// # Example
+///
+/// ```
+/// use pancurses::{Attribute, Attributes};
+/// use pancurses::colorpair::ColorPair;
+///
+/// let attributes = Attribute::Bold | ColorPair(3);
+/// assert!((attributes - ColorPair(2)).color_pair().0 == 3);
+/// assert_eq!(attributes - ColorPair(3), Attributes::from(Attribute::Bold));
+/// ```
+impl Sub<ColorPair> for Attributes {
+    type Output = Attributes;
+
+    fn sub(mut self, rhs: ColorPair) -> Attributes {
+        if self.color_pair() == rhs {
+            self.set_color_pair(ColorPair(0));
+        }
+        self
+    }
+}
+
 /// Implement the | operator for combining a `ColorPair` and an `Attribute` to produce `Attributes`
 ///
 /// # Example
@@ -0,0 +1,85 @@
+use crate::attributes::Attributes;
+use crate::colorpair::ColorPair;
+use crate::screenbuffer::Cell;
+
+/// The most combining characters curses will store alongside the base character of a cell.
+pub const MAX_COMBINING: usize = 4;
+
+/// A character to be drawn with `Window::add_wch()`: a base character, any combining characters
+/// that modify it, and the attributes (including color pair) to draw it with.
+///
+/// # Example
+///
+/// ```
+/// use pancurses::{Attribute, Attributes, ComplexChar};
+///
+/// let e_acute = ComplexChar::with_combining('e', &['\u{301}'], Attribute::Bold).unwrap();
+/// assert_eq!('e', e_acute.base());
+/// assert_eq!(&['\u{301}'], e_acute.combining());
+/// assert!(e_acute.attributes().is_bold());
+/// assert_eq!(None, ComplexChar::with_combining('e', &['\u{301}'; 5], Attributes::new()));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ComplexChar {
+    chars: Vec<char>,
+    attributes: Attributes,
+}
+
+impl ComplexChar {
+    /// Creates a ComplexChar from a single character.
+    pub fn new<A: Into<Attributes>>(ch: char, attributes: A) -> ComplexChar {
+        ComplexChar {
+            chars: vec![ch],
+            attributes: attributes.into(),
+        }
+    }
+
+    /// Creates a ComplexChar from a base character followed by combining characters. Returns
+    /// None if there are more than `MAX_COMBINING` combining characters.
+    pub fn with_combining<A: Into<Attributes>>(
+        base: char,
+        combining: &[char],
+        attributes: A,
+    ) -> Option<ComplexChar> {
+        if combining.len() > MAX_COMBINING {
+            return None;
+        }
+        let mut chars = Vec::with_capacity(combining.len() + 1);
+        chars.push(base);
+        chars.extend_from_slice(combining);
+        Some(ComplexChar {
+            chars,
+            attributes: attributes.into(),
+        })
+    }
+
+    /// Returns the attributes, including the color pair, this character is drawn with.
+    pub fn attributes(&self) -> Attributes {
+        self.attributes
+    }
+
+    /// Returns the base character.
+    pub fn base(&self) -> char {
+        self.chars[0]
+    }
+
+    /// Returns the base character followed by the combining characters.
+    pub fn chars(&self) -> &[char] {
+        &self.chars
+    }
+
+    /// Returns the combining characters, which may be empty.
+    pub fn combining(&self) -> &[char] {
+        &self.chars[1..]
+    }
+}
+
+/// Converts a Cell, which has no combining characters, folding its color pair into the
+/// attributes.
+impl From<Cell> for ComplexChar {
+    fn from(cell: Cell) -> ComplexChar {
+        let mut attributes = cell.attributes;
+        attributes.set_color_pair(ColorPair(cell.color_pair as u8));
+        ComplexChar::new(cell.ch, attributes)
+    }
+}
@@ -0,0 +1,75 @@
+use super::set_cursor_visibility;
+
+/// How the cursor is shown, as set by `set_cursor_visibility()`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CursorVisibility {
+    Invisible,
+    /// Usually an underline.
+    Normal,
+    /// Usually a block.
+    VeryVisible,
+}
+
+impl CursorVisibility {
+    /// Converts the value curs_set() takes and returns.
+    pub(crate) fn from_raw(visibility: i32) -> Option<CursorVisibility> {
+        match visibility {
+            0 => Some(CursorVisibility::Invisible),
+            1 => Some(CursorVisibility::Normal),
+            2 => Some(CursorVisibility::VeryVisible),
+            _ => None,
+        }
+    }
+}
+
+/// Converts to the value curs_set() takes.
+impl From<CursorVisibility> for i32 {
+    fn from(visibility: CursorVisibility) -> i32 {
+        match visibility {
+            CursorVisibility::Invisible => 0,
+            CursorVisibility::Normal => 1,
+            CursorVisibility::VeryVisible => 2,
+        }
+    }
+}
+
+/// Hides the cursor for as long as it's alive, returned by `hide_cursor_guard()`. Dropping it,
+/// including while unwinding from a panic, puts back the visibility the cursor had before.
+#[derive(Debug)]
+#[must_use = "the cursor is shown again as soon as the guard is dropped"]
+pub struct CursorGuard {
+    previous: Option<CursorVisibility>,
+}
+
+impl CursorGuard {
+    pub(crate) fn hide() -> CursorGuard {
+        CursorGuard {
+            previous: set_cursor_visibility(CursorVisibility::Invisible).ok(),
+        }
+    }
+}
+
+impl Drop for CursorGuard {
+    fn drop(&mut self) {
+        if let Some(previous) = self.previous {
+            let _ = set_cursor_visibility(previous);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_raw_visibility_round_trip() {
+        for &visibility in &[
+            CursorVisibility::Invisible,
+            CursorVisibility::Normal,
+            CursorVisibility::VeryVisible,
+        ] {
+            assert_eq!(Some(visibility), CursorVisibility::from_raw(visibility.into()));
+        }
+        assert_eq!(None, CursorVisibility::from_raw(-1));
+    }
+}
@@ -0,0 +1,139 @@
+use std::error::Error;
+use std::fmt;
+
+/// The reasons a checked drawing call such as `Window::try_mvaddstr()` can fail.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DrawError {
+    /// The position lies outside the window, so nothing was drawn.
+    OutOfBounds { y: i32, x: i32 },
+    /// The position was valid but curses itself reported an error, for instance because the text
+    /// ran past the bottom right corner of a window that doesn't scroll.
+    Curses(i32),
+}
+
+/// Implement Display for DrawError
+///
+/// # Example
+///
+/// ```
+/// use pancurses::DrawError;
+/// assert_eq!(
+///     DrawError::OutOfBounds { y: 3, x: -1 }.to_string(),
+///     "position (3, -1) is outside the window"
+/// );
+/// ```
+impl fmt::Display for DrawError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            DrawError::OutOfBounds { y, x } => {
+                write!(f, "position ({}, {}) is outside the window", y, x)
+            }
+            DrawError::Curses(code) => write!(f, "curses returned {}", code),
+        }
+    }
+}
+
+impl Error for DrawError {}
+
+/// The reasons `try_init_pair()` and the methods of a `ColorPairs` registry can fail.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ColorError {
+    /// start_color() hasn't been called, so no color pairs can be set up yet.
+    ColorsNotStarted,
+    /// The pair number isn't between 1 and max, inclusive.
+    PairOutOfRange { max: i32 },
+    /// A color isn't between 0 and max, inclusive, nor -1 for the default color.
+    ColorOutOfRange { max: i32 },
+    /// The arguments were in range but curses itself reported an error, for instance because -1
+    /// was used without use_default_colors().
+    Curses(i32),
+    /// Every color pair number a `ColorPairs` registry can hand out has been handed out.
+    PairsExhausted,
+    /// A `ColorPairs` registry already has this pair for other colors.
+    PairTaken { pair: i16 },
+}
+
+/// Implement Display for ColorError
+///
+/// # Example
+///
+/// ```
+/// use pancurses::ColorError;
+/// assert_eq!(
+///     ColorError::PairOutOfRange { max: 63 }.to_string(),
+///     "color pair numbers go from 1 to 63"
+/// );
+/// ```
+impl fmt::Display for ColorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ColorError::ColorsNotStarted => write!(f, "start_color() hasn't been called"),
+            ColorError::PairOutOfRange { max } => {
+                write!(f, "color pair numbers go from 1 to {}", max)
+            }
+            ColorError::ColorOutOfRange { max } => {
+                write!(f, "colors go from 0 to {}, or -1 for the default", max)
+            }
+            ColorError::Curses(code) => write!(f, "curses returned {}", code),
+            ColorError::PairsExhausted => write!(f, "no color pairs are left to allocate"),
+            ColorError::PairTaken { pair } => {
+                write!(f, "color pair {} is already in use for other colors", pair)
+            }
+        }
+    }
+}
+
+impl Error for ColorError {}
+
+/// Returned by `set_cursor_visibility()` when the terminal can't show the cursor that way.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct CursorUnsupported;
+
+impl fmt::Display for CursorUnsupported {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "the terminal can't change the cursor's visibility that way")
+    }
+}
+
+impl Error for CursorUnsupported {}
+
+/// Returned by `try_enable_mouse()` when the terminal can't report mouse events.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct MouseUnsupported;
+
+impl fmt::Display for MouseUnsupported {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "the terminal doesn't support a mouse")
+    }
+}
+
+impl Error for MouseUnsupported {}
+
+/// The reasons `ThemeBuilder::build()` can fail.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ThemeError {
+    /// More than one style was given this name.
+    DuplicateStyle(String),
+    /// A color pair for the styles couldn't be set up, for instance because they use more
+    /// combinations of colors than there are color pairs.
+    Color(ColorError),
+}
+
+impl fmt::Display for ThemeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ThemeError::DuplicateStyle(ref name) => {
+                write!(f, "the style \"{}\" is defined more than once", name)
+            }
+            ThemeError::Color(error) => error.fmt(f),
+        }
+    }
+}
+
+impl Error for ThemeError {}
+
+impl From<ColorError> for ThemeError {
+    fn from(error: ColorError) -> ThemeError {
+        ThemeError::Color(error)
+    }
+}
@@ -1,4 +1,6 @@
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+use crate::platform_specific;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum Input {
     Character(char),
     Unknown(i32),
@@ -28,6 +30,10 @@ pub enum Input {
     KeyF13,
     KeyF14,
     KeyF15,
+    /// Function keys 16 up to 63, which curses has codes for but no variants of their own.
+    /// Terminals commonly send Shift+F1..F12 as F13..F24. Lower numbers are accepted by
+    /// ungetch() too, but getch() returns the numbered variants for them.
+    KeyF(u8),
 
     KeyDL,
     KeyIL,
@@ -110,6 +116,13 @@ pub enum Input {
     KeyUndo,
 
     KeyResize,
+    /// Returned by getch() in place of KeyResize once `set_auto_resize()` is enabled, with the
+    /// screen size after the resize.
+    Resize { rows: i32, cols: i32 },
+    /// Text pasted while `enable_bracketed_paste()` is in effect, delivered in one piece rather
+    /// than as a keystroke per character. KeyEnter in a paste becomes a newline, and other keys
+    /// that arrive during it are returned after it.
+    Paste(String),
     KeyEvent,
     KeyMouse,
 
@@ -119,3 +132,71 @@ pub enum Input {
     KeyC1,
     KeyC3,
 }
+
+impl Input {
+    /// Converts a key code as returned by the underlying curses getch() to an Input. This is the
+    /// inverse of to_keycode(), for storing keys as numbers in config files or replay logs.
+    ///
+    /// Key codes are backend specific: the same key has different codes on ncurses and
+    /// PDCurses, and codes past KeyF15 or bound with define_key() can differ between terminals.
+    /// Codes that are only part of a character, such as a single byte of a UTF-8 sequence on
+    /// ncurses, and codes without a variant come back as Unknown.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pancurses::Input;
+    ///
+    /// for input in &[Input::Character('q'), Input::KeyUp, Input::KeyF(20)] {
+    ///     let code = input.to_keycode().unwrap();
+    ///     assert_eq!(*input, Input::from_keycode(code));
+    /// }
+    /// ```
+    pub fn from_keycode(code: i32) -> Input {
+        platform_specific::from_keycode(code)
+    }
+
+    /// Returns a human readable name for the input, as curses' keyname() gives it.
+    ///
+    /// Control characters are named like "^C", special keys like "KEY_LEFT" or "KEY_F(2)" and
+    /// any other character is returned as itself. Inputs curses has no name for are named like
+    /// "UNKNOWN(410)".
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pancurses::{initscr, endwin, Input};
+    ///
+    /// initscr();
+    /// assert_eq!("^C", Input::Character('\u{3}').name());
+    /// assert_eq!("q", Input::Character('q').name());
+    /// assert_eq!("é", Input::Character('é').name());
+    /// assert_eq!("KEY_LEFT", Input::KeyLeft.name());
+    /// assert_eq!("KEY_F(2)", Input::KeyF2.name());
+    /// assert_eq!("UNKNOWN(-7)", Input::Unknown(-7).name());
+    /// endwin();
+    /// ```
+    pub fn name(&self) -> String {
+        let code = match *self {
+            Input::Character(c) if c.is_ascii_control() => c as i32,
+            Input::Character(c) => return c.to_string(),
+            ref other => match platform_specific::to_keycode(other) {
+                Some(code) => code,
+                None => return format!("{:?}", other),
+            },
+        };
+        match platform_specific::_keyname(code) {
+            Some(ref name) if !name.is_empty() && name != "UNKNOWN KEY" => name.clone(),
+            _ => format!("UNKNOWN({})", code),
+        }
+    }
+
+    /// Converts this Input to the key code the underlying curses getch() returns for it. See
+    /// from_keycode() for how portable the codes are.
+    ///
+    /// Returns None for inputs that aren't a single code: characters getch() returns in several
+    /// parts, pastes, and keys the backend has no code for. Resize shares the code of KeyResize.
+    pub fn to_keycode(&self) -> Option<i32> {
+        platform_specific::to_keycode(self)
+    }
+}
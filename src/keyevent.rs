@@ -0,0 +1,116 @@
+use crate::input::Input;
+
+/// A key press along with the modifier keys that were held down, as returned by
+/// `Window::get_key_event()`.
+///
+/// Which combinations can be told apart depends on the terminal. Combinations that aren't
+/// recognized come through as the plain key with every modifier false.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct KeyEvent {
+    pub key: Input,
+    pub ctrl: bool,
+    pub alt: bool,
+    pub shift: bool,
+}
+
+impl KeyEvent {
+    /// Creates a KeyEvent for the key without any modifiers.
+    pub fn new(key: Input) -> KeyEvent {
+        KeyEvent {
+            key,
+            ctrl: false,
+            alt: false,
+            shift: false,
+        }
+    }
+
+    /// Splits the shifted keys curses has codes of its own for, such as KeySLeft, into the
+    /// unshifted key and the shift modifier.
+    pub(crate) fn from_shifted(input: Input) -> KeyEvent {
+        let key = match input {
+            Input::KeySLeft => Input::KeyLeft,
+            Input::KeySRight => Input::KeyRight,
+            Input::KeySR => Input::KeyUp,
+            Input::KeySF => Input::KeyDown,
+            Input::KeySHome => Input::KeyHome,
+            Input::KeySEnd => Input::KeyEnd,
+            Input::KeySIC => Input::KeyIC,
+            Input::KeySDC => Input::KeyDC,
+            Input::KeySPrevious => Input::KeyPPage,
+            Input::KeySNext => Input::KeyNPage,
+            Input::KeyBTab => Input::Character('\t'),
+            other => return KeyEvent::new(other),
+        };
+        KeyEvent {
+            shift: true,
+            ..KeyEvent::new(key)
+        }
+    }
+
+    /// Parses the names ncurses gives the extended key capabilities xterm-like terminals
+    /// describe, such as "kLFT5" for Ctrl+Left. The digit encodes the modifiers as one plus a
+    /// bit mask of shift (1), alt (2) and ctrl (4).
+    #[cfg(unix)]
+    pub(crate) fn from_extended_name(name: &str) -> Option<KeyEvent> {
+        let split = name.find(|c: char| c.is_ascii_digit())?;
+        let (base, digit) = name.split_at(split);
+        let key = match base {
+            "kUP" => Input::KeyUp,
+            "kDN" => Input::KeyDown,
+            "kLFT" => Input::KeyLeft,
+            "kRIT" => Input::KeyRight,
+            "kHOM" => Input::KeyHome,
+            "kEND" => Input::KeyEnd,
+            "kIC" => Input::KeyIC,
+            "kDC" => Input::KeyDC,
+            "kPRV" => Input::KeyPPage,
+            "kNXT" => Input::KeyNPage,
+            _ => return None,
+        };
+        let mask = match digit.parse::<u8>() {
+            Ok(n) if (2..=8).contains(&n) => n - 1,
+            _ => return None,
+        };
+        Some(KeyEvent {
+            key,
+            ctrl: mask & 4 != 0,
+            alt: mask & 2 != 0,
+            shift: mask & 1 != 0,
+        })
+    }
+}
+
+/// Converts an Input to a KeyEvent for the key without any modifiers.
+impl From<Input> for KeyEvent {
+    fn from(key: Input) -> KeyEvent {
+        KeyEvent::new(key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shifted_keys_are_split() {
+        let event = KeyEvent::from_shifted(Input::KeySLeft);
+        assert_eq!(Input::KeyLeft, event.key);
+        assert!(event.shift && !event.ctrl && !event.alt);
+        assert_eq!(KeyEvent::new(Input::KeyLeft), KeyEvent::from_shifted(Input::KeyLeft));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_extended_names_are_parsed() {
+        let ctrl_left = KeyEvent::from_extended_name("kLFT5").unwrap();
+        assert_eq!(Input::KeyLeft, ctrl_left.key);
+        assert!(ctrl_left.ctrl && !ctrl_left.alt && !ctrl_left.shift);
+
+        let all = KeyEvent::from_extended_name("kDC8").unwrap();
+        assert!(all.ctrl && all.alt && all.shift);
+
+        assert_eq!(None, KeyEvent::from_extended_name("kLFT1"));
+        assert_eq!(None, KeyEvent::from_extended_name("kxIN"));
+        assert_eq!(None, KeyEvent::from_extended_name("KEY_LEFT"));
+    }
+}
@@ -5,13 +5,23 @@ extern crate log;
 
 extern crate libc;
 
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(all(test, feature = "serde"))]
+extern crate serde_test;
+
 #[cfg(unix)]
 extern crate ncurses;
 #[cfg(windows)]
 extern crate pdcurses;
 
-use std::ffi::CString;
+use std::ffi::{CStr, CString};
+use std::io;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
 use std::ptr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, MutexGuard};
 
 #[cfg(windows)]
 use pdcurses as curses;
@@ -29,16 +39,50 @@ pub use ncurses::ll::{chtype, mmask_t, MEVENT, SCREEN};
 #[cfg(unix)]
 type ScrPtr = SCREEN;
 #[cfg(unix)]
+#[allow(clippy::upper_case_acronyms)]
 type FILE = curses::FILE_p;
 
+#[macro_use]
+mod macros;
+
 mod input;
 pub use self::input::*;
 
+pub mod keyevent;
+pub use crate::keyevent::KeyEvent;
+
 mod attributes;
 pub use self::attributes::*;
 
+pub mod border;
+pub use crate::border::BorderChars;
+
+pub mod color;
+pub use crate::color::Color;
+
 pub mod colorpair;
-pub use crate::colorpair::ColorPair;
+pub use crate::colorpair::{ColorPair, ColorPairs};
+
+pub mod complexchar;
+pub use crate::complexchar::ComplexChar;
+
+pub mod cursor;
+pub use crate::cursor::{CursorGuard, CursorVisibility};
+
+pub mod error;
+pub use crate::error::{ColorError, CursorUnsupported, DrawError, MouseUnsupported, ThemeError};
+
+pub mod mouse;
+pub use crate::mouse::{MouseEvent, MouseEventKind, MouseMask, DEFAULT_MOUSE_EVENTS};
+
+pub mod rgb;
+pub use crate::rgb::{nearest_color, Rgb};
+
+pub mod screenbuffer;
+pub use crate::screenbuffer::{Cell, ScreenBuffer};
+
+pub mod theme;
+pub use crate::theme::{Theme, ThemeBuilder};
 
 #[cfg(windows)]
 mod windows;
@@ -60,6 +104,15 @@ pub const ERR: i32 = -1;
 mod window;
 pub use crate::window::Window;
 
+static AUTO_RESIZE: AtomicBool = AtomicBool::new(false);
+static BRACKETED_PASTE: AtomicBool = AtomicBool::new(false);
+static MOUSE_MOTION_TRACKING: AtomicBool = AtomicBool::new(false);
+// The mask enable_mouse_motion_tracking() replaced, to go back to when tracking is turned off
+static MOUSE_MASK_BEFORE_TRACKING: Mutex<Option<MouseMask>> = Mutex::new(None);
+static PALETTE_CHANGED: AtomicBool = AtomicBool::new(false);
+static RESTORE_PALETTE: AtomicBool = AtomicBool::new(true);
+static SAVED_PALETTE: Mutex<Vec<(i16, i16, i16)>> = Mutex::new(Vec::new());
+
 pub trait ToChtype {
     fn to_chtype(&self) -> chtype;
 }
@@ -76,7 +129,103 @@ impl ToChtype for chtype {
     }
 }
 
+/// Copies a string curses returned, usually out of a static buffer that a later call overwrites.
+/// A null pointer gives an empty string.
+fn copy_c_string(string: *const libc::c_char) -> String {
+    if string.is_null() {
+        String::new()
+    } else {
+        unsafe { CStr::from_ptr(string) }.to_string_lossy().into_owned()
+    }
+}
+
+/// Opens the file at path with the given fopen() mode, for the curses functions that take a FILE.
+fn fopen(path: &Path, mode: &str) -> io::Result<*mut libc::FILE> {
+    let path = path
+        .to_str()
+        .and_then(|path| CString::new(path).ok())
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "unsupported file path"))?;
+    let mode = CString::new(mode).unwrap();
+    let file = unsafe { libc::fopen(path.as_ptr(), mode.as_ptr()) };
+    if file.is_null() {
+        Err(io::Error::last_os_error())
+    } else {
+        Ok(file)
+    }
+}
+
+/// Returns a color-pair for the given foreground and background, initializing an unused pair
+/// for the combination if none has it yet. When every pair is taken, the least recently
+/// allocated one is reused. Returns ERR if no pair could be found.
+///
+/// This leaves the bookkeeping for dynamically chosen colors to ncurses, and the pairs it
+/// returns can be used with attr_set_extended() and color_set_extended(). Needs the same ncurses
+/// as init_extended_pair(); returns ERR elsewhere, including PDCurses. Pairs handed out this way
+/// shouldn't also be set with init_pair(), nor mixed with a `ColorPairs` registry.
+///
+/// # Example
+///
+/// ```
+/// use pancurses::{alloc_pair, endwin, find_pair, free_pair, has_colors, initscr};
+/// use pancurses::{start_color, COLOR_CYAN, COLOR_RED, ERR, OK};
+///
+/// initscr();
+/// if has_colors() {
+///     start_color();
+///     let pair = alloc_pair(i32::from(COLOR_RED), i32::from(COLOR_CYAN));
+///     if pair != ERR {
+///         assert_eq!(pair, find_pair(i32::from(COLOR_RED), i32::from(COLOR_CYAN)));
+///         assert_eq!(OK, free_pair(pair));
+///         assert_eq!(ERR, find_pair(i32::from(COLOR_RED), i32::from(COLOR_CYAN)));
+///     }
+/// }
+/// endwin();
+/// ```
+pub fn alloc_pair(foreground: i32, background: i32) -> i32 {
+    platform_specific::_alloc_pair(foreground, background)
+}
+
+/// Sets the colors color-pair 0 is drawn with, and the colors -1 stands for in `init_pair()`, to
+/// the given foreground and background. Either may be -1 to keep the terminal's own default for
+/// it, so `assume_default_colors(-1, -1)` is the same as use_default_colors().
+///
+/// Call this after `start_color()`. It returns ERR if the terminal can't keep separate default
+/// colors.
+///
+/// # Example
+///
+/// ```
+/// use pancurses::{assume_default_colors, endwin, has_colors, initscr, start_color};
+/// use pancurses::COLOR_YELLOW;
+///
+/// let window = initscr();
+/// if has_colors() {
+///     start_color();
+///     // Yellow text on the terminal's own background, without any color pair.
+///     assume_default_colors(COLOR_YELLOW, -1);
+/// }
+/// window.printw("Warning");
+/// endwin();
+/// ```
+pub fn assume_default_colors(foreground: i16, background: i16) -> i32 {
+    unsafe { curses::assume_default_colors(i32::from(foreground), i32::from(background)) }
+}
+
 /// Return the output speed of the terminal. On Windows it simply returns `INT_MAX`
+///
+/// The speed is in bits per second, as the terminal settings report it, so a low value is a hint
+/// to keep updates small. Call this after `initscr()`.
+///
+/// # Example
+///
+/// ```
+/// use pancurses::{baudrate, endwin, initscr};
+///
+/// initscr();
+/// let animate = baudrate() >= 9600;
+/// endwin();
+/// println!("Animations {}", if animate { "on" } else { "off" });
+/// ```
 pub fn baudrate() -> i32 {
     unsafe { curses::baudrate() }
 }
@@ -86,7 +235,8 @@ pub fn beep() -> i32 {
     unsafe { curses::beep() }
 }
 
-/// Indicates if the terminal has the capability to change the definition of its colors.
+/// Indicates if the terminal has the capability to change the definition of its colors, with
+/// init_color() or init_color_rgb(). Call this after `start_color()`.
 pub fn can_change_color() -> bool {
     unsafe { curses::can_change_color() != 0 }
 }
@@ -116,41 +266,92 @@ pub fn COLOR_PAIRS() -> i32 {
 /// that tell you the amounts of red, green, and blue components in the given color. The argument
 /// must be a legal color value, i.e., 0 through COLORS()-1, inclusive. The values that are returned
 /// are in the range 0 (no component) through 1000 (maximum amount of component), inclusive.
+/// Returns None if the color isn't valid or colors haven't been started.
+///
+/// Querying the palette at startup makes it possible to put it back before exiting.
 ///
 /// ```rust
 /// use pancurses::{can_change_color, color_content, endwin, init_color, initscr, start_color};
+/// use pancurses::COLORS;
 ///
 /// initscr();
 /// start_color();
-/// if can_change_color() {
-///     init_color(8, 35, 502, 1000);
-///     let (r, g, b) = color_content(8);
-///     assert_eq!(35, r);
-///     assert_eq!(502, g);
-///     assert_eq!(1000, b);
+/// if can_change_color() && COLORS() > 100 {
+///     init_color(100, 123, 456, 789);
+///     assert_eq!(Some((123, 456, 789)), color_content(100));
 /// }
+/// assert_eq!(None, color_content(-2));
 /// endwin();
 /// ```
-pub fn color_content(color_number: i16) -> (i16, i16, i16) {
+pub fn color_content(color_number: i16) -> Option<(i16, i16, i16)> {
     let mut r: i16 = 0;
     let mut g: i16 = 0;
     let mut b: i16 = 0;
-    unsafe {
-        curses::color_content(color_number, &mut r, &mut g, &mut b);
+    match unsafe { curses::color_content(color_number, &mut r, &mut g, &mut b) } {
+        ERR => None,
+        _ => Some((r, g, b)),
     }
-    (r, g, b)
+}
+
+/// Returns the definition of a color like color_content(), converted to the 0 to 255 scale.
+/// Colors set with init_color_rgb() come back exactly as they were given.
+pub fn color_content_rgb(color_number: i16) -> Option<Rgb> {
+    color_content(color_number).map(|(r, g, b)| Rgb::from_curses(r, g, b))
+}
+
+/// Returns the number of color-pairs the terminal supports, the same as COLOR_PAIRS(). This is
+/// only meaningful once `start_color()` has been called, and is 0 before that.
+pub fn color_pairs() -> i32 {
+    COLOR_PAIRS()
+}
+
+/// Returns the number of colors the terminal supports, the same as COLORS(). This is only
+/// meaningful once `start_color()` has been called, and is 0 before that.
+///
+/// Together with color_pairs() and can_change_color() this is enough to pick a color scheme
+/// the terminal can show.
+///
+/// # Example
+///
+/// ```
+/// use pancurses::{can_change_color, color_pairs, colors, endwin, has_colors, initscr};
+/// use pancurses::start_color;
+///
+/// initscr();
+/// if has_colors() {
+///     start_color();
+/// }
+/// let theme = if can_change_color() {
+///     "rgb"
+/// } else if colors() >= 256 && color_pairs() >= 256 {
+///     "256 colors"
+/// } else if colors() >= 8 {
+///     "8 colors"
+/// } else {
+///     "monochrome"
+/// };
+/// endwin();
+/// println!("Using the {} theme", theme);
+/// ```
+pub fn colors() -> i32 {
+    COLORS()
 }
 
 /// Alters the appearance of the cursor.
 ///
 /// A visibility of 0 makes it disappear; 1 makes it appear "normal" (usually an underline) and 2
-/// makes it "highly visible" (usually a block).
+/// makes it "highly visible" (usually a block). Returns the previous visibility, or ERR if the
+/// terminal can't show the cursor that way. set_cursor_visibility() and hide_cursor_guard() do the
+/// same with a `CursorVisibility`.
 pub fn curs_set(visibility: i32) -> i32 {
     unsafe { curses::curs_set(visibility) }
 }
 
 /// Save the current terminal modes as the "program" (in curses) state for use by the
 /// `reset_prog_mode()` and `reset_shell_mode()` functions.  This is done automatically by initscr().
+///
+/// To run another program in the terminal, `suspend_tui()` does this along with the rest of
+/// leaving and resuming curses.
 pub fn def_prog_mode() -> i32 {
     unsafe { curses::def_prog_mode() }
 }
@@ -161,6 +362,30 @@ pub fn def_shell_mode() -> i32 {
     unsafe { curses::def_shell_mode() }
 }
 
+/// Binds an escape sequence sent by the terminal to a key code, so that once `keypad()` is enabled
+/// `getch()` returns the code instead of the individual characters of the sequence. Codes without
+/// an Input variant come through as `Input::Unknown(keycode)`. A keycode of zero or less removes
+/// the binding.
+///
+/// Only supported by ncurses; PDCurses returns `ERR`.
+///
+/// # Example
+///
+/// ```
+/// use pancurses::{define_key, initscr, endwin, key_defined, OK};
+///
+/// initscr();
+/// let ctrl_right = 1000;
+/// if cfg!(unix) {
+///     assert_eq!(OK, define_key("\x1b[1;5C", ctrl_right));
+///     assert_eq!(ctrl_right, key_defined("\x1b[1;5C"));
+/// }
+/// endwin();
+/// ```
+pub fn define_key(sequence: &str, keycode: i32) -> i32 {
+    platform_specific::_define_key(sequence, keycode)
+}
+
 /// Inserts an 'milliseconds' millisecond pause in output. This routine should not be used extensively
 /// because padding characters are used rather than a CPU pause. If no padding character is
 /// specified, this uses napms to perform the delay.
@@ -174,6 +399,7 @@ pub fn delay_output(milliseconds: i32) -> i32 {
 /// SCREEN is no longer needed.
 ///
 /// In PDCurses, the parameter must be the value of SP, and delscreen() sets SP to NULL.
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
 pub fn delscreen(screen: ScrPtr) {
     unsafe { curses::delscreen(screen) }
 }
@@ -184,6 +410,44 @@ pub fn doupdate() -> i32 {
     unsafe { curses::doupdate() }
 }
 
+/// Turns bracketed paste mode back off. See `enable_bracketed_paste()`.
+pub fn disable_bracketed_paste() -> i32 {
+    BRACKETED_PASTE.store(false, Ordering::Relaxed);
+    platform_specific::_set_bracketed_paste(false)
+}
+
+/// Stops reporting mouse motion, going back to the mouse mask that was set before
+/// `enable_mouse_motion_tracking()` was called.
+///
+/// # Example
+///
+/// ```
+/// use pancurses::{disable_mouse_motion_tracking, enable_mouse_motion_tracking, endwin};
+/// use pancurses::{initscr, set_mouse_mask, MouseMask};
+///
+/// let window = initscr();
+/// let clicks = MouseMask::new().button_click(1);
+/// set_mouse_mask(clicks);
+/// enable_mouse_motion_tracking();
+/// disable_mouse_motion_tracking();
+/// assert_eq!(clicks, set_mouse_mask(clicks));
+///
+/// // endwin() puts the mask back as well
+/// enable_mouse_motion_tracking();
+/// endwin();
+/// window.refresh();
+/// assert_eq!(clicks, set_mouse_mask(clicks));
+/// endwin();
+/// ```
+pub fn disable_mouse_motion_tracking() -> i32 {
+    MOUSE_MOTION_TRACKING.store(false, Ordering::Relaxed);
+    // Turning the mouse off and on again has ncurses restore its own tracking mode
+    let current = set_mouse_mask(MouseMask::new());
+    let result = platform_specific::_set_mouse_motion_tracking(false);
+    set_mouse_mask(mask_before_tracking().take().unwrap_or(current));
+    result
+}
+
 /// Enabled echoing typed characters.
 ///
 /// Initially, input characters are echoed. Subsequent calls to echo() and noecho() do not flush
@@ -192,15 +456,113 @@ pub fn echo() -> i32 {
     unsafe { curses::echo() }
 }
 
+/// Asks the terminal to mark pasted text, so that getch() returns a whole paste as a single
+/// `Input::Paste` instead of a keystroke per character. This keeps a pasted newline from
+/// triggering whatever Enter is bound to.
+///
+/// Terminals without bracketed paste ignore the request and pastes keep arriving as plain
+/// characters. Recognizing the markers needs keypad mode to be on for the window that reads
+/// them. endwin() turns the mode off again so the shell isn't left with it, so call this again
+/// after resuming from a temporary escape. Not supported on PDCurses, where this returns ERR.
+///
+/// # Example
+///
+/// ```no_run
+/// use pancurses::{enable_bracketed_paste, endwin, initscr, Input};
+///
+/// let window = initscr();
+/// window.keypad(true);
+/// enable_bracketed_paste();
+/// match window.getch() {
+///     Some(Input::Paste(text)) => {
+///         window.addstr(&text);
+///     }
+///     Some(Input::Character('\n')) => { /* run the command */ }
+///     _ => {}
+/// }
+/// endwin();
+/// ```
+pub fn enable_bracketed_paste() -> i32 {
+    BRACKETED_PASTE.store(true, Ordering::Relaxed);
+    platform_specific::_set_bracketed_paste(true)
+}
+
+/// Reports every button event and every movement of the pointer, with or without a button held,
+/// for drag to select and the like. The motion comes through `Window::get_mouse_event()` with a
+/// kind of `MouseEventKind::Move`.
+///
+/// This replaces the mouse mask with `MouseMask::all_buttons().with_motion()`, until
+/// disable_mouse_motion_tracking() or endwin() puts the previous mask back. ncurses on its own
+/// only asks xterm-like terminals for motion while a button is held, so the sequence for
+/// reporting any motion is sent as well. endwin() turns that off again so the shell isn't
+/// flooded with motion reports, so call this again after resuming from a temporary escape.
+///
+/// # Example
+///
+/// ```no_run
+/// use pancurses::{enable_mouse_motion_tracking, endwin, initscr, Input, MouseEventKind};
+///
+/// let window = initscr();
+/// window.keypad(true);
+/// enable_mouse_motion_tracking();
+/// while let Some(Input::KeyMouse) = window.getch() {
+///     match window.get_mouse_event() {
+///         Ok(event) if event.kind == MouseEventKind::Move => {
+///             window.mvaddstr(0, 0, format!("{:3} {:3}", event.y, event.x));
+///         }
+///         _ => break,
+///     }
+/// }
+/// endwin();
+/// ```
+pub fn enable_mouse_motion_tracking() -> i32 {
+    MOUSE_MOTION_TRACKING.store(true, Ordering::Relaxed);
+    let previous = set_mouse_mask(MouseMask::all_buttons().with_motion());
+    // Enabling it twice mustn't make the motion mask the one to go back to
+    mask_before_tracking().get_or_insert(previous);
+    platform_specific::_set_mouse_motion_tracking(true)
+}
+
 /// Should be called before exiting or escaping from curses mode temporarily.
 ///
 /// It will restore tty modes, move the cursor to the lower left corner of the screen and reset the
 /// terminal into the proper non-visual mode.  To resume curses after a temporary escape, call
-/// refresh() or doupdate().
+/// refresh() or doupdate(). Bracketed paste mode and mouse motion tracking are turned off if
+/// they were enabled, the latter putting back the mouse mask it replaced, and the palette is put
+/// back with restore_default_palette() if init_color() changed it, unless
+/// set_restore_palette_on_exit(false) was called.
 pub fn endwin() -> i32 {
+    if RESTORE_PALETTE.load(Ordering::Relaxed) && PALETTE_CHANGED.load(Ordering::Relaxed) {
+        restore_default_palette();
+    }
+    if BRACKETED_PASTE.swap(false, Ordering::Relaxed) {
+        platform_specific::_set_bracketed_paste(false);
+    }
+    if MOUSE_MOTION_TRACKING.swap(false, Ordering::Relaxed) {
+        platform_specific::_set_mouse_motion_tracking(false);
+        if let Some(mask) = mask_before_tracking().take() {
+            set_mouse_mask(mask);
+        }
+    }
     unsafe { curses::endwin() }
 }
 
+/// Returns the foreground and background colors of a pair set with init_extended_pair() or
+/// init_pair(), or None if the pair isn't valid.
+///
+/// Needs the same ncurses as init_extended_pair(). Returns None elsewhere, including PDCurses.
+pub fn extended_pair_content(pair: i32) -> Option<(i32, i32)> {
+    platform_specific::_extended_pair_content(pair)
+}
+
+/// Returns the color-pair alloc_pair() handed out for the given foreground and background, or
+/// ERR if it hasn't handed one out. Unlike alloc_pair() this never initializes a pair.
+///
+/// Needs the same ncurses as init_extended_pair(). Returns ERR elsewhere, including PDCurses.
+pub fn find_pair(foreground: i32, background: i32) -> i32 {
+    platform_specific::_find_pair(foreground, background)
+}
+
 /// Flashes the screen, if possible; if not, it calls beep().
 pub fn flash() -> i32 {
     unsafe { curses::flash() }
@@ -208,15 +570,73 @@ pub fn flash() -> i32 {
 
 /// Throws away any type-ahead that has been typed by the user and has not yet been read by the
 /// program.
+///
+/// Calling this once a slow operation finishes discards the keys pressed while the user was
+/// waiting, rather than draining them with nodelay() and a getch() loop.
+///
+/// # Example
+///
+/// ```
+/// use pancurses::{flushinp, initscr, endwin, napms, ungetch, Input};
+///
+/// let window = initscr();
+/// ungetch(&Input::Character('\n'));
+/// napms(10); // A long task, during which Enter was pressed
+/// flushinp();
+/// window.nodelay(true);
+/// assert_eq!(None, window.getch());
+/// endwin();
+/// ```
 pub fn flushinp() -> i32 {
     unsafe { curses::flushinp() }
 }
 
-/// Returns the current mouse status in an MEVENT struct.
+/// Marks a color-pair returned by alloc_pair() as unused, so that it can be handed out for
+/// another combination. Returns ERR if the pair wasn't allocated by alloc_pair().
+///
+/// Needs the same ncurses as init_extended_pair(). Returns ERR elsewhere, including PDCurses.
+pub fn free_pair(pair: i32) -> i32 {
+    platform_specific::_free_pair(pair)
+}
+
+/// Returns how many milliseconds curses waits after an ESC for the rest of an escape sequence,
+/// as set by set_escdelay(). Returns ERR on PDCurses, which doesn't support it.
+pub fn get_escdelay() -> i32 {
+    platform_specific::_get_escdelay()
+}
+
+/// Returns the current mouse status in an MEVENT struct. Use `MouseEvent::from()` to decode it.
 pub fn getmouse() -> Result<MEVENT, i32> {
     platform_specific::_getmouse()
 }
 
+/// Reads a window previously saved with Window::putwin() from the file at path, creating a new
+/// window with the saved size, contents and attributes.
+///
+/// ```rust
+/// use pancurses::{initscr, endwin, getwin, newwin, A_BOLD, A_CHARTEXT};
+/// let window = initscr();
+/// let path = std::env::temp_dir().join("pancurses-getwin-doctest");
+/// let original = newwin(3, 12, 1, 1);
+/// original.mvaddch(1, 1, 'x' as u32 | A_BOLD);
+/// original.putwin(&path).unwrap();
+/// let restored = getwin(&path).unwrap();
+/// assert_eq!(original.get_max_yx(), restored.get_max_yx());
+/// assert_eq!(original.mvinchstr(1, 0), restored.mvinchstr(1, 0));
+/// std::fs::remove_file(&path).unwrap();
+/// endwin();
+/// ```
+pub fn getwin<P: AsRef<Path>>(path: P) -> io::Result<Window> {
+    let file = fopen(path.as_ref(), "rb")?;
+    let window_pointer = unsafe { curses::getwin(file as FILE) };
+    unsafe { libc::fclose(file) };
+    if window_pointer.is_null() {
+        Err(io::Error::new(io::ErrorKind::InvalidData, "getwin failed"))
+    } else {
+        Ok(window::new_window(window_pointer, false))
+    }
+}
+
 /// Similar to cbreak(), but allows for a time limit to be specified, in tenths of a second.
 ///
 /// This causes getch() to block for that period before returning None if no key has been received.
@@ -230,9 +650,80 @@ pub fn has_colors() -> bool {
     unsafe { curses::has_colors() > 0 }
 }
 
+/// Indicates if the terminal can deliver the given key, according to its terminfo description.
+/// Characters, and keys the backend has no code for, always return false. On ncurses an
+/// `Input::Unknown` code is looked up too, so keys bound with define_key() are reported.
+///
+/// PDCurses has no terminal description to consult, so it reports every key it has a code for.
+///
+/// # Example
+///
+/// ```
+/// use pancurses::{define_key, has_key, initscr, endwin, Input};
+///
+/// initscr();
+/// let quit_hint = if has_key(Input::KeyF10) { "F10" } else { "Ctrl-Q" };
+/// assert!(!has_key(Input::Character('q')));
+/// if cfg!(unix) {
+///     assert!(!has_key(Input::Unknown(1001)));
+///     define_key("\x1b[1;6C", 1001);
+///     assert!(has_key(Input::Unknown(1001)));
+/// }
+/// endwin();
+/// ```
+pub fn has_key(input: Input) -> bool {
+    platform_specific::_has_key(&input)
+}
+
+/// Indicates if the terminal can report mouse events. This is only meaningful once mousemask()
+/// or set_mouse_mask() has been called with a nonzero mask, since ncurses doesn't set up the
+/// mouse before then; `try_enable_mouse()` does both in one call.
+pub fn has_mouse() -> bool {
+    platform_specific::_has_mouse()
+}
+
+/// Hides the cursor until the returned guard is dropped, which puts back the visibility the
+/// cursor had before. Since the guard is also dropped while unwinding, the cursor comes back
+/// after a panic too. If the terminal can't hide the cursor nothing happens.
+///
+/// # Example
+///
+/// ```
+/// use pancurses::{endwin, hide_cursor_guard, initscr};
+///
+/// let window = initscr();
+/// {
+///     let _hidden = hide_cursor_guard();
+///     window.mvaddstr(0, 0, "Drawing without a cursor jumping about");
+///     window.refresh();
+/// }
+/// // The cursor is shown again here
+/// endwin();
+/// ```
+pub fn hide_cursor_guard() -> CursorGuard {
+    CursorGuard::hide()
+}
+
 /// Initialize the curses system, this must be the first function that is called.
 ///
 /// Returns a Window struct that is used to access Window specific functions.
+///
+/// # Example
+///
+/// Most full screen programs set up input the same way: unbuffered and without signals, with
+/// typed characters not echoed and special keys decoded.
+///
+/// ```
+/// use pancurses::{initscr, endwin, noecho, raw};
+///
+/// let window = initscr();
+/// raw();
+/// noecho();
+/// window.keypad(true);
+/// window.printw("Ready");
+/// window.refresh();
+/// endwin();
+/// ```
 pub fn initscr() -> Window {
     platform_specific::pre_init();
     let window_pointer = unsafe { curses::initscr() };
@@ -245,26 +736,187 @@ pub fn initscr() -> Window {
 /// Each of the last three arguments must be a value in the range 0 through 1000. When `init_color`
 /// is used, all occurrences of that color on the screen immediately change to the new definition.
 pub fn init_color(color_number: i16, red: i16, green: i16, blue: i16) -> i32 {
-    unsafe { curses::init_color(color_number, red, green, blue) }
+    let result = unsafe { curses::init_color(color_number, red, green, blue) };
+    if result != ERR {
+        PALETTE_CHANGED.store(true, Ordering::Relaxed);
+    }
+    result
+}
+
+/// Changes the definition of a color like init_color(), taking the components on the usual 0 to
+/// 255 scale and converting them to the 0 to 1000 scale curses uses.
+///
+/// # Example
+///
+/// ```
+/// use pancurses::{can_change_color, color_content_rgb, endwin, init_color_rgb, initscr, Rgb};
+/// use pancurses::{start_color, COLORS};
+///
+/// initscr();
+/// start_color();
+/// if can_change_color() && COLORS() > 100 {
+///     let orange = Rgb::from_hex("#ff8000").unwrap();
+///     init_color_rgb(100, orange);
+///     assert_eq!(Some(orange), color_content_rgb(100));
+/// }
+/// endwin();
+/// ```
+pub fn init_color_rgb(color_number: i16, rgb: Rgb) -> i32 {
+    let (red, green, blue) = rgb.to_curses();
+    init_color(color_number, red, green, blue)
+}
+
+/// Changes the definition of a color-pair, like init_pair() but with int arguments so that pair
+/// numbers past the 32767 a short can hold are usable on terminals with that many pairs.
+///
+/// Select such pairs with `Window::attr_set_extended()` or `Window::color_set_extended()`, since
+/// the color bits of a chtype run out far sooner. Needs the wide ncurses library, which the
+/// `wide` feature links against, in version 6.1 or later. Returns ERR with other builds of
+/// ncurses and on PDCurses, which don't have the function.
+///
+/// # Example
+///
+/// ```
+/// use pancurses::{endwin, extended_pair_content, has_colors, initscr, init_extended_pair};
+/// use pancurses::{start_color, COLOR_BLACK, COLOR_RED, OK};
+///
+/// let window = initscr();
+/// if has_colors() {
+///     start_color();
+///     if init_extended_pair(1, COLOR_RED.into(), COLOR_BLACK.into()) == OK {
+///         let content = extended_pair_content(1);
+///         assert_eq!(Some((COLOR_RED.into(), COLOR_BLACK.into())), content);
+///         assert_eq!(OK, window.color_set_extended(1));
+///     }
+/// }
+/// endwin();
+/// ```
+pub fn init_extended_pair(pair: i32, foreground: i32, background: i32) -> i32 {
+    platform_specific::_init_extended_pair(pair, foreground, background)
 }
 
 /// Changes the definition of a color-pair.
 ///
 /// It takes three arguments: the number of the color-pair to be redefined, and the new values of
 /// the foreground and background colors. The pair number must be between 0 and `COLOR_PAIRS` - 1,
-/// inclusive. The foreground and background must be between 0 and `COLORS()` - 1, inclusive. If the
-/// color pair was previously initialized, the screen is refreshed, and all occurrences of that
-/// color-pair are changed to the new definition.
+/// inclusive. The foreground and background must be between 0 and `COLORS()` - 1, inclusive, or -1
+/// for the terminal's default color once use_default_colors() or assume_default_colors() has been
+/// called. If the color pair was previously initialized, the screen is refreshed, and all
+/// occurrences of that color-pair are changed to the new definition.
+///
+/// # Example
+///
+/// ```
+/// use pancurses::{endwin, has_colors, init_pair, initscr, pair_content, start_color};
+/// use pancurses::{use_default_colors, COLOR_GREEN, COLOR_PAIR, OK};
+///
+/// let window = initscr();
+/// if has_colors() {
+///     start_color();
+///     if use_default_colors() == OK {
+///         assert_eq!(OK, init_pair(1, COLOR_GREEN, -1));
+///         assert_eq!(Some((COLOR_GREEN, -1)), pair_content(1));
+///         window.attrset(COLOR_PAIR(1));
+///     }
+/// }
+/// window.printw("Green on the terminal's background");
+/// endwin();
+/// ```
 pub fn init_pair(pair_index: i16, foreground_color: i16, background_color: i16) -> i32 {
     unsafe { curses::init_pair(pair_index, foreground_color, background_color) as i32 }
 }
 
+/// Changes the definition of a color-pair like init_pair(), taking the colors as `Color`s.
+///
+/// # Example
+///
+/// ```
+/// use pancurses::{endwin, has_colors, init_pair_colors, initscr, pair_content, start_color};
+/// use pancurses::{Color, COLOR_BLUE, COLOR_WHITE};
+///
+/// initscr();
+/// if has_colors() {
+///     start_color();
+///     init_pair_colors(1, Color::White, Color::Blue);
+///     assert_eq!(Some((COLOR_WHITE, COLOR_BLUE)), pair_content(1));
+/// }
+/// endwin();
+/// ```
+pub fn init_pair_colors(pair_index: i16, foreground: Color, background: Color) -> i32 {
+    init_pair(pair_index, foreground.into(), background.into())
+}
+
+/// Changes the definition of a color-pair to the given foreground and background Rgbs, as closely
+/// as the terminal allows.
+///
+/// If can_change_color() is true, colors `256 + 2 * (pair_index - 1)` and the one after it are
+/// redefined to the exact Rgbs with init_color_rgb() and used for the pair. The colors of the
+/// xterm 256 color palette are never redefined, since nearest_color() and `Color::Number` rely
+/// on them, so this only happens on terminals with more colors than that. Otherwise, or if those
+/// colors are past `COLORS()`, the closest entries of the xterm 256 color palette are used, as
+/// picked by nearest_color(). That needs a terminal with 256 colors, so elsewhere this returns
+/// ERR.
+///
+/// # Example
+///
+/// ```
+/// use pancurses::{color_content, endwin, has_colors, init_pair_rgb, initscr, start_color};
+/// use pancurses::{Rgb, COLOR_PAIR};
+///
+/// let window = initscr();
+/// if has_colors() {
+///     start_color();
+///     let orange = Rgb::from_hex("#ff8000").unwrap();
+///     let cube_entry = color_content(16);
+///     init_pair_rgb(1, orange, Rgb::new(0, 0, 0));
+///     window.attrset(COLOR_PAIR(1));
+///     // The palette nearest_color() picks from is left as it was
+///     assert_eq!(cube_entry, color_content(16));
+/// }
+/// window.printw("Orange, or as close as the terminal gets");
+/// endwin();
+/// ```
+pub fn init_pair_rgb(pair_index: i16, foreground: Rgb, background: Rgb) -> i32 {
+    // Pair n takes the two colors from 256 + 2 * (n - 1), past the xterm 256 color palette
+    let first_color = 256 + 2 * (i32::from(pair_index) - 1);
+    if can_change_color() && pair_index >= 1 && first_color + 1 < COLORS() {
+        let (foreground_color, background_color) = (first_color as i16, first_color as i16 + 1);
+        if init_color_rgb(foreground_color, foreground) == ERR
+            || init_color_rgb(background_color, background) == ERR
+        {
+            return ERR;
+        }
+        init_pair(pair_index, foreground_color, background_color)
+    } else {
+        init_pair(pair_index, nearest_color(foreground), nearest_color(background))
+    }
+}
+
 /// Sets the timeout for a mouse click.
 ///
 /// Sets the maximum time (in thousands of a second) that can elapse between press and release
 /// events for them to be recognized as aclick. Use mouseinterval(0) to disable click resolution.
 /// This function returns the previous interval value. Use mouseinterval(-1) to obtain the interval
 /// without altering it. The default is one sixth of a second.
+///
+/// While click resolution is on, curses holds back a press until it knows whether a release
+/// follows in time, and then reports the pair as a single BUTTONx_CLICKED event (or
+/// DOUBLE_CLICKED and TRIPLE_CLICKED for quick repeats). That wait makes dragging feel laggy.
+/// With mouseinterval(0) every press and release is reported right away as BUTTONx_PRESSED and
+/// BUTTONx_RELEASED and the CLICKED bits never show up, so the mouse mask needs to include the
+/// press and release events for anything to be reported at all.
+///
+/// # Example
+///
+/// ```
+/// use pancurses::{endwin, initscr, mouseinterval, set_mouse_mask, MouseMask};
+///
+/// initscr();
+/// mouseinterval(0);
+/// assert_eq!(0, mouseinterval(-1));
+/// set_mouse_mask(MouseMask::new().button_press(1).button_release(1));
+/// endwin();
+/// ```
 pub fn mouseinterval(interval: i32) -> i32 {
     unsafe { curses::mouseinterval(interval) }
 }
@@ -278,11 +930,31 @@ pub fn mouseinterval(interval: i32) -> i32 {
 ///
 /// As a side effect, setting a zero mousemask may turn off the mouse pointer; setting a nonzero
 /// mask may turn it on. Whether this happens is device-dependent.
+///
+/// `DEFAULT_MOUSE_EVENTS` is a good starting point that includes the scroll wheel.
 pub fn mousemask(newmask: mmask_t, oldmask: Option<&mut mmask_t>) -> mmask_t {
     let oldmask_ptr = oldmask.map(|x| x as *mut _).unwrap_or(std::ptr::null_mut());
     unsafe { curses::mousemask(newmask, oldmask_ptr) }
 }
 
+/// Returns true if the screen would change size if resizeterm() were called with the given
+/// number of lines and columns, meaning curses hasn't caught up with the terminal yet.
+///
+/// PDCurses can't compare against a given size, and instead reports whether the user has resized
+/// the console since the last resize_term().
+pub fn is_term_resized(lines: i32, cols: i32) -> bool {
+    platform_specific::_is_term_resized(lines, cols)
+}
+
+/// Returns the key code bound to the escape sequence, 0 if nothing is bound to it or -1 if a
+/// longer bound sequence starts with it. Check this before overriding a binding with
+/// `define_key()`.
+///
+/// Only supported by ncurses; PDCurses always returns 0.
+pub fn key_defined(sequence: &str) -> i32 {
+    platform_specific::_key_defined(sequence)
+}
+
 /// Returns a character string corresponding to the key `code`.
 ///
 /// * Printable characters are displayed as themselves, e.g., a one-character string containing the
@@ -301,6 +973,13 @@ pub fn keyname(code: i32) -> Option<String> {
     platform_specific::_keyname(code)
 }
 
+/// Returns a verbose description of the terminal, such as "xterm terminal emulator (X Window
+/// System)" on ncurses. PDCurses describes the port in use instead, for instance "PDCurses for
+/// Windows". Call this after `initscr()`.
+pub fn longname() -> String {
+    copy_c_string(unsafe { curses::longname() })
+}
+
 /// Suspends the program for the specified number of milliseconds.
 pub fn napms(ms: i32) -> i32 {
     unsafe { curses::napms(ms) }
@@ -316,6 +995,7 @@ pub fn napms(ms: i32) -> i32 {
 ///
 /// (For the PDCurses backend it's just an alternative interface for initscr(). It always returns
 /// SP, or NULL.)
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
 pub fn newterm(t: Option<&str>, output: FILE, input: FILE) -> ScrPtr {
     let term_type = t.map(|x| CString::new(x).unwrap());
     let type_ptr = match term_type {
@@ -325,6 +1005,25 @@ pub fn newterm(t: Option<&str>, output: FILE, input: FILE) -> ScrPtr {
     unsafe { curses::newterm(type_ptr, output, input) }
 }
 
+/// Creates a new pad with the given number of lines, nlines and columns, ncols.
+///
+/// A pad is like a window, except that it is not associated with a part of the screen and may
+/// be larger than it. Use the pad's prefresh() or pnoutrefresh() to display part of it, rather
+/// than refresh(), which doesn't know which part of the pad to show.
+///
+/// ```rust
+/// use pancurses::{initscr, endwin, newpad, OK};
+/// let window = initscr();
+/// let pad = newpad(500, 80);
+/// pad.mvaddstr(499, 0, "Last line");
+/// assert_eq!(OK, pad.prefresh(480, 0, 0, 0, 9, 39));
+/// endwin();
+/// ```
+pub fn newpad(nlines: i32, ncols: i32) -> Window {
+    let window_pointer = unsafe { curses::newpad(nlines, ncols) };
+    window::new_window(window_pointer, false)
+}
+
 /// Creates a new window with the given number of lines, nlines and columns, ncols.
 ///
 /// The upper left corner of the window is at line begy, column begx. If nlines is zero, it
@@ -367,7 +1066,8 @@ pub fn nonl() -> i32 {
     unsafe { curses::nonl() }
 }
 
-/// Disable raw mode.
+/// Disable raw mode, returning to the line buffered mode the terminal started in. Call cbreak()
+/// afterwards to keep unbuffered input but get signals and flow control back.
 ///
 /// Raw mode is similar to cbreak mode, in that characters typed are immediately passed through to
 /// the user program. The difference is that in raw mode, the INTR, QUIT, SUSP, and STOP characters
@@ -376,15 +1076,91 @@ pub fn noraw() -> i32 {
     unsafe { curses::noraw() }
 }
 
+/// Brings curses up to date after the terminal has been resized and returns the new number of
+/// rows and columns. Call it when getch() returns `Input::KeyResize`, then resize or recreate
+/// your windows to fit.
+///
+/// ncurses has already adjusted the screen by the time it reports `KEY_RESIZE`, so there this
+/// only queries the size. PDCurses needs `resize_term(0, 0)` called first, which this does.
+///
+/// # Example
+///
+/// ```
+/// use pancurses::{initscr, endwin, on_resize};
+///
+/// let window = initscr();
+/// assert_eq!(window.get_max_yx(), on_resize());
+/// endwin();
+/// ```
+pub fn on_resize() -> (i32, i32) {
+    platform_specific::_on_resize()
+}
+
+/// Returns the foreground and background colors of a color pair, as set by init_pair(), or None
+/// if the pair number isn't valid or colors haven't been started.
+///
+/// # Example
+///
+/// ```
+/// use pancurses::{endwin, has_colors, init_pair, initscr, pair_content, start_color};
+/// use pancurses::{COLOR_BLUE, COLOR_WHITE};
+///
+/// initscr();
+/// if has_colors() {
+///     start_color();
+///     init_pair(1, COLOR_WHITE, COLOR_BLUE);
+///     assert_eq!(Some((COLOR_WHITE, COLOR_BLUE)), pair_content(1));
+///     assert_eq!(None, pair_content(-1));
+/// }
+/// endwin();
+/// ```
+pub fn pair_content(pair: i16) -> Option<(i16, i16)> {
+    let mut foreground: i16 = 0;
+    let mut background: i16 = 0;
+    match unsafe { curses::pair_content(pair, &mut foreground, &mut background) } {
+        ERR => None,
+        _ => Some((foreground, background)),
+    }
+}
+
 /// Enable raw mode.
 ///
 /// Raw mode is similar to cbreak mode, in that characters typed are immediately passed through to
 /// the user program. The difference is that in raw mode, the INTR, QUIT, SUSP, and STOP characters
 /// are passed through without being interpreted, and without generating a signal.
+///
+/// So with cbreak() Ctrl-C still interrupts the program and Ctrl-S/Ctrl-Q still pause and resume
+/// output, while with raw() they arrive as `Input::Character('\u{3}')`, `'\u{13}'` and `'\u{11}'`
+/// and the program has to provide its own way to quit or suspend.
+///
+/// # Example
+///
+/// ```
+/// use pancurses::{initscr, endwin, noecho, raw, Input};
+///
+/// let window = initscr();
+/// raw();
+/// noecho();
+/// window.keypad(true);
+/// window.nodelay(true);
+/// if let Some(Input::Character('\u{3}')) = window.getch() {
+///     // Ctrl-C was pressed, and no SIGINT was sent
+/// }
+/// endwin();
+/// ```
 pub fn raw() -> i32 {
     unsafe { curses::raw() }
 }
 
+/// Discards the definitions of all color-pairs, as if init_pair() had never been called, so that
+/// pair numbers can be handed out afresh. Pairs handed out by alloc_pair() are released too, but
+/// a `ColorPairs` registry doesn't know about this and has to be replaced.
+///
+/// Needs ncurses 6.1 or later; returns ERR with older versions and on PDCurses.
+pub fn reset_color_pairs() -> i32 {
+    platform_specific::_reset_color_pairs()
+}
+
 /// Restore the terminal to "program" (in curses) state. This is done
 /// automatically by endwin() and doupdate() after an endwin(), so this would normally not be
 /// called before.
@@ -398,19 +1174,191 @@ pub fn reset_shell_mode() -> i32 {
     unsafe { curses::reset_shell_mode() }
 }
 
+/// Restores the terminal modes saved by the last savetty().
+///
+/// The prog and shell modes are the two states curses itself switches between: endwin() and
+/// reset_shell_mode() put the terminal into the shell modes, refresh() after an endwin() and
+/// reset_prog_mode() put it back into the program modes, and def_prog_mode()/def_shell_mode()
+/// update what those are. savetty() and resetty() are a separate snapshot of the modes that
+/// curses never takes or restores by itself, so it stays exactly as saved. Use them to get back
+/// to a known state at points of the program's own choosing, for instance after a child process
+/// that was run without leaving curses mode has changed the modes. To hand the terminal over to
+/// another program for a while, use suspend_tui() instead.
+///
+/// # Example
+///
+/// ```
+/// use pancurses::{endwin, initscr, noecho, raw, resetty, savetty};
+///
+/// initscr();
+/// raw();
+/// noecho();
+/// savetty();
+/// // ... run something that leaves the terminal in cooked mode with echo on
+/// resetty(); // Back to raw mode without echo
+/// endwin();
+/// ```
+pub fn resetty() -> i32 {
+    unsafe { curses::resetty() }
+}
+
 /// Attempts to resize the screen to the given size.
 ///
 /// `resize_term()` is effectively two functions: When called with nonzero values for nlines and
 /// ncols, it attempts to resize the screen to the given size. When called with (0, 0), it merely
 /// adjusts the internal structures to match the current size after the screen is resized by the
 /// user. If you want to support user resizing, you should check for getch() returning `KEY_RESIZE`,
-/// and/or call `is_termresized()` at appropriate times; if either condition occurs, call
+/// and/or call `is_term_resized()` at appropriate times; if either condition occurs, call
 /// `resize_term(0, 0)`. Then, with either user or programmatic resizing, you'll have to resize any
 /// windows you've created.
 pub fn resize_term(nlines: i32, ncols: i32) -> i32 {
     platform_specific::_resize_term(nlines, ncols)
 }
 
+/// Checks the size of the terminal and, if it differs from what curses has, resizes the screen
+/// to match with resizeterm(), returning the new number of lines and columns. Returns None if the
+/// size hasn't changed or can't be found out.
+///
+/// This is for programs that notice a resize themselves, for instance by handling SIGWINCH, and
+/// don't wait for getch() to return `Input::KeyResize`. On ncurses the size is read from the
+/// terminal with the TIOCGWINSZ ioctl on standard output. On PDCurses this is resize_term(0, 0),
+/// if is_term_resized() says the console was resized. Either way the contents of windows are
+/// kept, as far as they still fit, so only what has newly come into view needs drawing.
+///
+/// # Example
+///
+/// ```
+/// use pancurses::{endwin, initscr, resize_to_terminal};
+///
+/// let window = initscr();
+/// if let Some((lines, cols)) = resize_to_terminal() {
+///     assert_eq!((lines, cols), window.get_max_yx());
+/// }
+/// endwin();
+/// ```
+pub fn resize_to_terminal() -> Option<(i32, i32)> {
+    platform_specific::_resize_to_terminal()
+}
+
+/// Resizes the screen to the given number of lines and columns, keeping the contents of
+/// windows as far as they fit. With ncurses, windows that would no longer fit on the screen are
+/// shrunk, and `Input::KeyResize` is pushed so that the program's usual resize handling runs.
+///
+/// Unlike resize_term(), which only adjusts curses' own structures, resizeterm() is what a
+/// program should call when it learns that the terminal has changed size. On PDCurses the two
+/// are the same.
+///
+/// # Example
+///
+/// ```
+/// use pancurses::{endwin, initscr, is_term_resized, resizeterm, OK};
+///
+/// let window = initscr();
+/// window.mvaddstr(1, 2, "Still here");
+/// assert_eq!(OK, resizeterm(30, 100));
+/// assert_eq!((30, 100), window.get_max_yx());
+/// assert!(!is_term_resized(30, 100));
+/// assert!(is_term_resized(20, 100));
+/// assert_eq!("  Still here", window.snapshot().line(1));
+/// endwin();
+/// ```
+pub fn resizeterm(lines: i32, cols: i32) -> i32 {
+    platform_specific::_resizeterm(lines, cols)
+}
+
+/// Puts back the colors start_color() found when it was called, undoing any changes made with
+/// init_color() since. Some terminals keep a changed palette after the program exits, so
+/// endwin() calls this by itself; see set_restore_palette_on_exit().
+///
+/// Returns ERR if there is nothing to restore, because start_color() hasn't been called or the
+/// terminal can't change its colors.
+///
+/// # Example
+///
+/// ```
+/// use pancurses::{can_change_color, color_content, endwin, init_color, initscr};
+/// use pancurses::{restore_default_palette, start_color};
+///
+/// initscr();
+/// start_color();
+/// if can_change_color() {
+///     let original = color_content(1);
+///     init_color(1, 1000, 500, 0);
+///     restore_default_palette();
+///     assert_eq!(original, color_content(1));
+/// }
+/// endwin();
+/// ```
+pub fn restore_default_palette() -> i32 {
+    let palette = saved_palette();
+    if palette.is_empty() {
+        return ERR;
+    }
+    let mut result = OK;
+    for (color_number, &(red, green, blue)) in palette.iter().enumerate() {
+        if unsafe { curses::init_color(color_number as i16, red, green, blue) } == ERR {
+            result = ERR;
+        }
+    }
+    PALETTE_CHANGED.store(false, Ordering::Relaxed);
+    result
+}
+
+fn saved_palette() -> MutexGuard<'static, Vec<(i16, i16, i16)>> {
+    match SAVED_PALETTE.lock() {
+        Ok(palette) => palette,
+        Err(poisoned) => poisoned.into_inner(),
+    }
+}
+
+fn mask_before_tracking() -> MutexGuard<'static, Option<MouseMask>> {
+    match MOUSE_MASK_BEFORE_TRACKING.lock() {
+        Ok(mask) => mask,
+        Err(poisoned) => poisoned.into_inner(),
+    }
+}
+
+/// Saves the current terminal modes for a later resetty(), which explains how this differs from
+/// def_prog_mode(). Returns ERR if the modes couldn't be read, as when the output isn't a
+/// terminal.
+pub fn savetty() -> i32 {
+    unsafe { curses::savetty() }
+}
+
+/// Makes getch() handle `KEY_RESIZE` itself when enabled: it calls `on_resize()` and returns
+/// `Input::Resize` with the new size instead of `Input::KeyResize`. The setting applies to every
+/// window and is off by default, leaving the resize to the program.
+///
+/// # Example
+///
+/// ```
+/// use pancurses::{initscr, endwin, set_auto_resize, Input};
+///
+/// let window = initscr();
+/// set_auto_resize(true);
+/// window.ungetch(&Input::KeyResize);
+/// let (rows, cols) = window.get_max_yx();
+/// assert_eq!(Some(Input::Resize { rows, cols }), window.getch());
+/// set_auto_resize(false);
+/// window.ungetch(&Input::KeyResize);
+/// assert_eq!(Some(Input::KeyResize), window.getch());
+/// endwin();
+/// ```
+pub fn set_auto_resize(enabled: bool) {
+    AUTO_RESIZE.store(enabled, Ordering::Relaxed);
+}
+
+/// Applies `set_auto_resize()` to input read by getch().
+fn auto_resize(input: Option<Input>) -> Option<Input> {
+    match input {
+        Some(Input::KeyResize) if AUTO_RESIZE.load(Ordering::Relaxed) => {
+            let (rows, cols) = on_resize();
+            Some(Input::Resize { rows, cols })
+        }
+        other => other,
+    }
+}
+
 /// Toggles whether the `A_BLINK` attribute sets an actual blink mode (TRUE), or sets the background
 /// color to high intensity (FALSE).
 ///
@@ -422,6 +1370,76 @@ pub fn set_blink(enabled: bool) -> i32 {
     platform_specific::_set_blink(enabled)
 }
 
+/// Sets how the cursor is shown, like curs_set(), returning its previous visibility. Fails with
+/// CursorUnsupported if the terminal can't show the cursor that way.
+///
+/// # Example
+///
+/// ```
+/// use pancurses::{endwin, initscr, set_cursor_visibility, CursorVisibility};
+///
+/// initscr();
+/// if let Ok(previous) = set_cursor_visibility(CursorVisibility::Invisible) {
+///     // ...
+///     assert_eq!(Ok(CursorVisibility::Invisible), set_cursor_visibility(previous));
+/// }
+/// endwin();
+/// ```
+pub fn set_cursor_visibility(
+    visibility: CursorVisibility,
+) -> Result<CursorVisibility, CursorUnsupported> {
+    CursorVisibility::from_raw(curs_set(visibility.into())).ok_or(CursorUnsupported)
+}
+
+/// Sets how many milliseconds curses waits after an ESC for the rest of an escape sequence
+/// before returning the ESC as a key of its own. The default of a second makes a bare Escape
+/// key feel sluggish; a few dozen milliseconds is plenty on a local terminal, though slow
+/// connections may split function key sequences if it's too short.
+///
+/// This overrides the ESCDELAY environment variable. Returns ERR on PDCurses, which doesn't
+/// support it.
+///
+/// # Example
+///
+/// ```
+/// use pancurses::{endwin, get_escdelay, initscr, set_escdelay, OK};
+///
+/// initscr();
+/// if cfg!(unix) {
+///     assert_eq!(OK, set_escdelay(25));
+///     assert_eq!(25, get_escdelay());
+/// }
+/// endwin();
+/// ```
+pub fn set_escdelay(milliseconds: i32) -> i32 {
+    platform_specific::_set_escdelay(milliseconds)
+}
+
+/// Sets the mouse events to be reported, as mousemask() does, and returns the previous mask.
+///
+/// # Example
+///
+/// ```
+/// use pancurses::{endwin, initscr, set_mouse_mask, MouseMask};
+///
+/// initscr();
+/// let clicks = MouseMask::new().button_click(1);
+/// set_mouse_mask(clicks);
+/// assert_eq!(clicks, set_mouse_mask(MouseMask::new()));
+/// endwin();
+/// ```
+pub fn set_mouse_mask(mask: MouseMask) -> MouseMask {
+    let mut oldmask = 0;
+    mousemask(mask.bits(), Some(&mut oldmask));
+    MouseMask::from(oldmask)
+}
+
+/// Sets whether endwin() restores the palette start_color() found, if init_color() has changed
+/// it. This is on by default; turn it off to leave the changed colors in place after exiting.
+pub fn set_restore_palette_on_exit(restore: bool) {
+    RESTORE_PALETTE.store(restore, Ordering::Relaxed);
+}
+
 /// Switches between different terminals.
 ///
 /// The screen reference new becomes the new current terminal. The previous terminal is returned by
@@ -430,6 +1448,7 @@ pub fn set_blink(enabled: bool) -> i32 {
 ///
 /// (Does nothing meaningful in PDCurses, but is included for compatibility with other curses
 /// implementations.)
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
 pub fn set_term(new: ScrPtr) -> ScrPtr {
     unsafe { curses::set_term(new) }
 }
@@ -443,14 +1462,313 @@ pub fn set_title(title: &str) {
 /// Initializes eight basic colors (black, red, green, yellow, blue, magenta, cyan,
 /// and white), and two global variables accessed through `COLORS()` and `COLOR_PAIRS()` (respectively defining the
 /// maximum number of colors and color-pairs the terminal is capable of displaying).
+///
+/// If the terminal can change its colors, the palette is saved for restore_default_palette(). Only
+/// the first 256 colors are saved.
 pub fn start_color() -> i32 {
-    unsafe { curses::start_color() as i32 }
+    let result = unsafe { curses::start_color() as i32 };
+    if result != ERR && can_change_color() {
+        let mut palette = saved_palette();
+        if palette.is_empty() {
+            *palette = (0..COLORS().min(256) as i16)
+                .map(|color_number| color_content(color_number).unwrap_or((0, 0, 0)))
+                .collect();
+        }
+    }
+    result
+}
+
+/// Leaves curses mode, runs f, and then resumes curses with the screen redrawn, returning what f
+/// returned. This is for handing the terminal over to another program, such as `$EDITOR` or
+/// `$PAGER`, which expects it in its normal state.
+///
+/// The program modes are saved with def_prog_mode() and endwin() is called, which also turns off
+/// bracketed paste and mouse motion tracking and restores the palette. Afterwards
+/// reset_prog_mode() and refresh() bring curses back, the two modes are turned on again if they
+/// were on, and colors changed with init_color() are set to what they were before. If f panics,
+/// curses is resumed all the same before the panic carries on.
+///
+/// # Example
+///
+/// ```
+/// use pancurses::{can_change_color, color_content, endwin, has_colors, init_color, initscr};
+/// use pancurses::{start_color, suspend_tui};
+/// use std::process::Command;
+///
+/// let window = initscr();
+/// window.printw("Running a command...");
+/// let status = suspend_tui(|| Command::new("true").status());
+/// window.mvaddstr(1, 0, format!("It exited with {:?}", status.ok()));
+///
+/// // The program gets the default palette, but the changed colors come back afterwards
+/// if has_colors() {
+///     start_color();
+/// }
+/// if can_change_color() {
+///     init_color(1, 1000, 500, 0);
+///     suspend_tui(|| ());
+///     assert_eq!(Some((1000, 500, 0)), color_content(1));
+/// }
+///
+/// // Curses is resumed before a panic in the closure reaches the caller
+/// let caught = std::panic::catch_unwind(|| suspend_tui(|| panic!("the editor crashed")));
+/// assert!(caught.is_err());
+/// window.mvaddstr(2, 0, "Still in curses mode");
+/// window.refresh();
+/// endwin();
+/// ```
+pub fn suspend_tui<F: FnOnce() -> R, R>(f: F) -> R {
+    let bracketed_paste = BRACKETED_PASTE.load(Ordering::Relaxed);
+    let mouse_motion_tracking = MOUSE_MOTION_TRACKING.load(Ordering::Relaxed);
+    // endwin() restores the default palette for f, so the colors in use are kept to set again
+    let palette: Vec<_> =
+        if RESTORE_PALETTE.load(Ordering::Relaxed) && PALETTE_CHANGED.load(Ordering::Relaxed) {
+            (0..COLORS().min(256) as i16)
+                .map(|color_number| color_content(color_number).unwrap_or((0, 0, 0)))
+                .collect()
+        } else {
+            Vec::new()
+        };
+    def_prog_mode();
+    endwin();
+
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+
+    reset_prog_mode();
+    for (color_number, &(red, green, blue)) in palette.iter().enumerate() {
+        init_color(color_number as i16, red, green, blue);
+    }
+    unsafe { curses::refresh() };
+    if bracketed_paste {
+        enable_bracketed_paste();
+    }
+    if mouse_motion_tracking {
+        enable_mouse_motion_tracking();
+    }
+    match result {
+        Ok(result) => result,
+        Err(payload) => panic::resume_unwind(payload),
+    }
+}
+
+/// Returns the short name of the terminal, as the TERM environment variable gave it on ncurses
+/// and "pdcurses" on PDCurses. Call this after `initscr()`.
+///
+/// # Example
+///
+/// ```
+/// use pancurses::{endwin, initscr, longname, termname};
+///
+/// initscr();
+/// let terminal = format!("{} ({})", termname(), longname());
+/// endwin();
+/// assert!(!terminal.starts_with(" ("));
+/// ```
+pub fn termname() -> String {
+    copy_c_string(unsafe { curses::termname() })
+}
+
+/// Returns the value of a boolean terminfo capability of the terminal, such as "am" for
+/// automatic margins, or None if the name isn't a boolean capability. A capability the terminal
+/// lacks is Some(false). Call this after `initscr()`.
+///
+/// Only ncurses reads terminfo; PDCurses always returns None.
+pub fn tigetflag(capability: &str) -> Option<bool> {
+    platform_specific::_tigetflag(capability)
+}
+
+/// Returns the value of a numeric terminfo capability of the terminal, such as "colors", or None
+/// if the terminal lacks it or the name isn't a numeric capability. Call this after `initscr()`.
+///
+/// Only ncurses reads terminfo; PDCurses always returns None.
+pub fn tigetnum(capability: &str) -> Option<i32> {
+    platform_specific::_tigetnum(capability)
+}
+
+/// Returns the value of a string terminfo capability of the terminal, or None if the terminal
+/// lacks it or the name isn't a string capability. Extended capabilities, such as "Ms" for
+/// setting the clipboard or "Smulx" for styled underlines, can be read as well if the terminal
+/// description has them. Call this after `initscr()`.
+///
+/// Only ncurses reads terminfo; PDCurses always returns None.
+///
+/// # Example
+///
+/// ```
+/// use pancurses::{endwin, initscr, tigetflag, tigetnum, tigetstr, tparm1};
+///
+/// initscr();
+/// if cfg!(unix) {
+///     assert_eq!(None, tigetstr("not_a_capability"));
+///     assert_eq!(None, tigetnum("cup"));
+///     assert!(tigetflag("am").is_some());
+///     if let Some(set_foreground) = tigetstr("setaf") {
+///         // Typically "\x1b[31m" for red
+///         assert!(tparm1(&set_foreground, 1).is_some());
+///     }
+/// }
+/// endwin();
+/// ```
+pub fn tigetstr(capability: &str) -> Option<Vec<u8>> {
+    platform_specific::_tigetstr(capability)
+}
+
+/// Substitutes a single numeric parameter into a string capability returned by tigetstr(),
+/// such as "setaf" or "cuu", giving the bytes to write to the terminal. Returns None if the
+/// capability can't be expanded.
+///
+/// Only ncurses reads terminfo; PDCurses always returns None.
+pub fn tparm1(capability: &[u8], parameter: i32) -> Option<Vec<u8>> {
+    platform_specific::_tparm1(capability, parameter)
+}
+
+/// Sets the mouse mask and checks that the terminal has a mouse. Returns the events out of mask
+/// that can actually be reported, or MouseUnsupported if none can, in which case the mask is
+/// reset so nothing is left half enabled.
+///
+/// # Example
+///
+/// ```
+/// use pancurses::{endwin, initscr, try_enable_mouse, MouseMask};
+///
+/// let window = initscr();
+/// let hint = match try_enable_mouse(MouseMask::all_buttons()) {
+///     Ok(_) => "Click to select",
+///     Err(_) => "Press Enter to select",
+/// };
+/// window.addstr(hint);
+/// endwin();
+/// ```
+pub fn try_enable_mouse(mask: MouseMask) -> Result<MouseMask, MouseUnsupported> {
+    let available = MouseMask::from(mousemask(mask.bits(), None));
+    if has_mouse() && available != MouseMask::new() {
+        Ok(available)
+    } else {
+        mousemask(0, None);
+        Err(MouseUnsupported)
+    }
+}
+
+/// Changes the definition of a color-pair like init_pair(), but checks the arguments against
+/// `COLOR_PAIRS()` and `COLORS()` first, so that a mistake is reported rather than showing up
+/// as text in the wrong colors.
+///
+/// # Example
+///
+/// ```
+/// use pancurses::{endwin, has_colors, initscr, start_color, try_init_pair, ColorError};
+/// use pancurses::{COLORS, COLOR_BLACK, COLOR_PAIRS, COLOR_RED};
+///
+/// initscr();
+/// assert_eq!(Err(ColorError::ColorsNotStarted), try_init_pair(1, COLOR_RED, COLOR_BLACK));
+/// if has_colors() {
+///     start_color();
+///     assert_eq!(Ok(()), try_init_pair(1, COLOR_RED, COLOR_BLACK));
+///     assert_eq!(
+///         Err(ColorError::PairOutOfRange { max: COLOR_PAIRS() - 1 }),
+///         try_init_pair(0, COLOR_RED, COLOR_BLACK)
+///     );
+///     assert_eq!(
+///         Err(ColorError::ColorOutOfRange { max: COLORS() - 1 }),
+///         try_init_pair(1, COLOR_RED, COLORS() as i16)
+///     );
+/// }
+/// endwin();
+/// ```
+pub fn try_init_pair(
+    pair_index: i16,
+    foreground_color: i16,
+    background_color: i16,
+) -> Result<(), ColorError> {
+    let (max_pair, max_color) = (COLOR_PAIRS() - 1, COLORS() - 1);
+    if max_pair < 0 {
+        return Err(ColorError::ColorsNotStarted);
+    }
+    if pair_index < 1 || i32::from(pair_index) > max_pair {
+        return Err(ColorError::PairOutOfRange { max: max_pair });
+    }
+    let in_range = |color: i16| color >= -1 && i32::from(color) <= max_color;
+    if !in_range(foreground_color) || !in_range(background_color) {
+        return Err(ColorError::ColorOutOfRange { max: max_color });
+    }
+    match init_pair(pair_index, foreground_color, background_color) {
+        ERR => Err(ColorError::Curses(ERR)),
+        _ => Ok(()),
+    }
+}
+
+/// Sets the file descriptor curses checks for type-ahead while refreshing, or disables the check
+/// if fd is -1.
+///
+/// By default curses looks for pending input on its input stream during `refresh()` and
+/// `doupdate()`, and stops updating the screen early if the user has typed something, finishing
+/// on a later refresh. That keeps the program responsive to key repeat but can leave a long
+/// repaint half drawn. Disabling the check makes every refresh run to completion. The setting
+/// belongs to the current screen, so with several screens it has to be made for each of them.
+///
+/// # Example
+///
+/// ```
+/// use pancurses::{initscr, endwin, typeahead, OK};
+///
+/// let window = initscr();
+/// assert_eq!(OK, typeahead(-1));
+/// window.refresh();
+/// endwin();
+/// ```
+pub fn typeahead(fd: i32) -> i32 {
+    unsafe { curses::typeahead(fd) }
+}
+
+/// Places input back onto the input queue, to be returned by the next call to `getch()` on any
+/// window. The queue belongs to the screen rather than to a window, so no Window is needed, but
+/// ncurses returns `ERR` if `initscr()` hasn't been called yet.
+///
+/// Characters are pushed back as the bytes `getch()` decodes them from, and special keys as their
+/// key codes. Returns `ERR` for keys the backend has no code for.
+///
+/// # Example
+///
+/// ```
+/// use pancurses::{initscr, endwin, newwin, ungetch, Input};
+///
+/// let window = initscr();
+/// ungetch(&Input::KeyF5);
+/// assert_eq!(Some(Input::KeyF5), newwin(1, 1, 0, 0).getch());
+/// endwin();
+/// ```
+pub fn ungetch(input: &Input) -> i32 {
+    platform_specific::_ungetch(input)
+}
+
+/// Pushes a mouse event back onto the input queue, so that the next getch() returns KeyMouse
+/// and get_mouse_event() returns the event. The bstate sent is built from the event's decoded
+/// fields. Handy for tests and for replaying recorded input.
+///
+/// # Example
+///
+/// ```
+/// use pancurses::{endwin, initscr, set_mouse_mask, ungetmouse, Input, MouseEvent};
+/// use pancurses::{MouseEventKind, MouseMask};
+///
+/// let window = initscr();
+/// set_mouse_mask(MouseMask::all_buttons());
+/// let double_click = MouseEvent::new(5, 10, Some(1), MouseEventKind::DoubleClick);
+/// ungetmouse(&double_click);
+/// assert_eq!(Some(Input::KeyMouse), window.getch());
+/// assert_eq!(Ok(double_click), window.get_mouse_event());
+/// endwin();
+/// ```
+pub fn ungetmouse(event: &MouseEvent) -> i32 {
+    let mut mevent = MEVENT::from(*event);
+    unsafe { curses::ungetmouse(&mut mevent) }
 }
 
 /// Allows the use of -1 as a foreground or background color with `init_pair()`.
 ///
 /// Calls `assume_default_colors(-1, -1);` -1 represents the foreground or background color that
-/// the terminal had at startup.
+/// the terminal had at startup, so text drawn with such a pair keeps the user's theme, including
+/// a transparent background. Call this after `start_color()`.
 pub fn use_default_colors() -> i32 {
     unsafe { curses::use_default_colors() }
 }
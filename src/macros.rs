@@ -0,0 +1,37 @@
+/// Formats the arguments with Rust's formatting machinery and adds the result to the window at
+/// the current cursor position.
+///
+/// Unlike a C format string, nothing in the arguments is interpreted by curses, so text that
+/// happens to contain `%` is printed as-is.
+///
+/// ```rust
+/// use pancurses::{initscr, endwin, printw};
+/// let window = initscr();
+/// let (name, count) = ("done", 42);
+/// printw!(window, "{}: {:>5} (100%)", name, count);
+/// assert_eq!("done:    42 (100%)", window.mvinstr(0, 0));
+/// endwin();
+/// ```
+#[macro_export]
+macro_rules! printw {
+    ($window:expr, $($arg:tt)*) => {
+        $window.addstr(format!($($arg)*))
+    };
+}
+
+/// Moves the cursor, then formats the arguments with Rust's formatting machinery and adds the
+/// result to the window, as printw! does.
+///
+/// ```rust
+/// use pancurses::{initscr, endwin, mvprintw};
+/// let window = initscr();
+/// mvprintw!(window, 2, 4, "U+{:04X}", 0x263a);
+/// assert_eq!("U+263A", window.mvinstr(2, 4));
+/// endwin();
+/// ```
+#[macro_export]
+macro_rules! mvprintw {
+    ($window:expr, $y:expr, $x:expr, $($arg:tt)*) => {
+        $window.mvaddstr($y, $x, format!($($arg)*))
+    };
+}
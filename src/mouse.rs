@@ -0,0 +1,455 @@
+use std::ops::BitOr;
+use super::{mmask_t, ALL_MOUSE_EVENTS, MEVENT, REPORT_MOUSE_POSITION};
+use super::{BUTTON1_CLICKED, BUTTON1_PRESSED, BUTTON1_RELEASED};
+use super::{BUTTON1_DOUBLE_CLICKED, BUTTON1_TRIPLE_CLICKED};
+use super::{BUTTON2_CLICKED, BUTTON2_PRESSED, BUTTON2_RELEASED};
+use super::{BUTTON2_DOUBLE_CLICKED, BUTTON2_TRIPLE_CLICKED};
+use super::{BUTTON3_CLICKED, BUTTON3_PRESSED, BUTTON3_RELEASED};
+use super::{BUTTON3_DOUBLE_CLICKED, BUTTON3_TRIPLE_CLICKED};
+use super::{BUTTON4_CLICKED, BUTTON4_PRESSED, BUTTON4_RELEASED};
+use super::{BUTTON4_DOUBLE_CLICKED, BUTTON4_TRIPLE_CLICKED};
+use super::{BUTTON5_CLICKED, BUTTON5_PRESSED, BUTTON5_RELEASED};
+use super::{BUTTON5_DOUBLE_CLICKED, BUTTON5_TRIPLE_CLICKED};
+use super::{BUTTON_ALT, BUTTON_CTRL, BUTTON_SHIFT};
+
+/// A mouse mask to pass to `mousemask()` that reports presses, releases and clicks of the three
+/// buttons, the scroll wheel (which curses reports as buttons 4 and 5) and the modifier keys
+/// held down during any of them.
+pub const DEFAULT_MOUSE_EVENTS: mmask_t = BUTTON1_PRESSED
+    | BUTTON1_RELEASED
+    | BUTTON1_CLICKED
+    | BUTTON2_PRESSED
+    | BUTTON2_RELEASED
+    | BUTTON2_CLICKED
+    | BUTTON3_PRESSED
+    | BUTTON3_RELEASED
+    | BUTTON3_CLICKED
+    | BUTTON4_PRESSED
+    | BUTTON5_PRESSED
+    | BUTTON_SHIFT
+    | BUTTON_CTRL
+    | BUTTON_ALT;
+
+// The bstate bits of each button, in the order of the MouseEventKind they decode to
+const PRESSED: usize = 0;
+const RELEASED: usize = 1;
+const CLICKED: usize = 2;
+const DOUBLE_CLICKED: usize = 3;
+const TRIPLE_CLICKED: usize = 4;
+const BUTTON_MASKS: [[mmask_t; 5]; 5] = [
+    [
+        BUTTON1_PRESSED,
+        BUTTON1_RELEASED,
+        BUTTON1_CLICKED,
+        BUTTON1_DOUBLE_CLICKED,
+        BUTTON1_TRIPLE_CLICKED,
+    ],
+    [
+        BUTTON2_PRESSED,
+        BUTTON2_RELEASED,
+        BUTTON2_CLICKED,
+        BUTTON2_DOUBLE_CLICKED,
+        BUTTON2_TRIPLE_CLICKED,
+    ],
+    [
+        BUTTON3_PRESSED,
+        BUTTON3_RELEASED,
+        BUTTON3_CLICKED,
+        BUTTON3_DOUBLE_CLICKED,
+        BUTTON3_TRIPLE_CLICKED,
+    ],
+    [
+        BUTTON4_PRESSED,
+        BUTTON4_RELEASED,
+        BUTTON4_CLICKED,
+        BUTTON4_DOUBLE_CLICKED,
+        BUTTON4_TRIPLE_CLICKED,
+    ],
+    [
+        BUTTON5_PRESSED,
+        BUTTON5_RELEASED,
+        BUTTON5_CLICKED,
+        BUTTON5_DOUBLE_CLICKED,
+        BUTTON5_TRIPLE_CLICKED,
+    ],
+];
+
+/// The set of mouse events curses should report, for `set_mouse_mask()`. This wraps the
+/// BUTTON constants, whose values differ between ncurses and PDCurses.
+///
+/// # Example
+///
+/// ```
+/// use pancurses::MouseMask;
+///
+/// let mask = MouseMask::new().button_press(1).button_release(1).with_wheel();
+/// assert!(mask.contains(MouseMask::new().button_press(1)));
+/// assert!(!mask.contains(MouseMask::new().button_click(1)));
+/// assert!(MouseMask::all_buttons().with_motion().contains(mask));
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct MouseMask {
+    raw: mmask_t,
+}
+
+impl MouseMask {
+    /// A mask that reports nothing, which turns mouse reporting off.
+    pub const fn new() -> MouseMask {
+        MouseMask { raw: 0 }
+    }
+
+    /// Every press, release and click of every button including the wheel, along with the
+    /// modifier keys, but not motion.
+    pub const fn all_buttons() -> MouseMask {
+        MouseMask {
+            raw: ALL_MOUSE_EVENTS & !REPORT_MOUSE_POSITION,
+        }
+    }
+
+    /// Returns the raw mmask_t, as mousemask() takes it.
+    pub const fn bits(self) -> mmask_t {
+        self.raw
+    }
+
+    /// Reports presses of the button, from 1 to 5. Other buttons are ignored.
+    pub const fn button_press(self, button: u8) -> MouseMask {
+        self.with_button_event(button, PRESSED)
+    }
+
+    /// Reports releases of the button, from 1 to 5. Other buttons are ignored.
+    pub const fn button_release(self, button: u8) -> MouseMask {
+        self.with_button_event(button, RELEASED)
+    }
+
+    /// Reports clicks of the button, from 1 to 5. Other buttons are ignored. Clicks are only
+    /// told apart from a press and release within `mouseinterval()`.
+    pub const fn button_click(self, button: u8) -> MouseMask {
+        self.with_button_event(button, CLICKED)
+    }
+
+    /// Reports double clicks of the button, from 1 to 5. Other buttons are ignored.
+    pub const fn button_double_click(self, button: u8) -> MouseMask {
+        self.with_button_event(button, DOUBLE_CLICKED)
+    }
+
+    /// Reports triple clicks of the button, from 1 to 5. Other buttons are ignored.
+    pub const fn button_triple_click(self, button: u8) -> MouseMask {
+        self.with_button_event(button, TRIPLE_CLICKED)
+    }
+
+    /// Returns true if every event in other is also in this mask.
+    pub const fn contains(self, other: MouseMask) -> bool {
+        self.raw & other.raw == other.raw
+    }
+
+    /// Also reports the pointer moving.
+    pub const fn with_motion(self) -> MouseMask {
+        MouseMask {
+            raw: self.raw | REPORT_MOUSE_POSITION,
+        }
+    }
+
+    /// Also reports which of Shift, Ctrl and Alt were held down.
+    pub const fn with_modifiers(self) -> MouseMask {
+        MouseMask {
+            raw: self.raw | BUTTON_SHIFT | BUTTON_CTRL | BUTTON_ALT,
+        }
+    }
+
+    /// Also reports the scroll wheel, which curses treats as presses of buttons 4 and 5.
+    pub const fn with_wheel(self) -> MouseMask {
+        self.button_press(4).button_press(5)
+    }
+
+    const fn with_button_event(self, button: u8, event: usize) -> MouseMask {
+        if button < 1 || button as usize > BUTTON_MASKS.len() {
+            return self;
+        }
+        MouseMask {
+            raw: self.raw | BUTTON_MASKS[button as usize - 1][event],
+        }
+    }
+}
+
+/// Implement the | operator for combining MouseMasks
+impl BitOr for MouseMask {
+    type Output = MouseMask;
+
+    fn bitor(self, rhs: MouseMask) -> MouseMask {
+        MouseMask {
+            raw: self.raw | rhs.raw,
+        }
+    }
+}
+
+impl From<mmask_t> for MouseMask {
+    fn from(raw: mmask_t) -> MouseMask {
+        MouseMask { raw }
+    }
+}
+
+impl From<MouseMask> for mmask_t {
+    fn from(mask: MouseMask) -> mmask_t {
+        mask.raw
+    }
+}
+
+/// What happened in a MouseEvent.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MouseEventKind {
+    Press,
+    Release,
+    Click,
+    DoubleClick,
+    TripleClick,
+    /// The wheel was turned away from the user, which curses reports as a press of button 4.
+    ScrollUp,
+    /// The wheel was turned towards the user, which curses reports as a press of button 5.
+    ScrollDown,
+    /// The pointer moved, either with no button held or while the mask includes
+    /// REPORT_MOUSE_POSITION.
+    Move,
+}
+
+/// A mouse event read with `getmouse()`, with the bstate bit mask decoded. The constants behind
+/// bstate differ between ncurses and PDCurses, but the decoded fields mean the same on both.
+///
+/// The coordinates are relative to the screen; `Window::mouse_trafo()` converts them to a
+/// window's.
+///
+/// # Example
+///
+/// ```
+/// use pancurses::{MouseEvent, MouseEventKind, BUTTON1_CLICKED, BUTTON4_PRESSED, BUTTON_SHIFT};
+/// use pancurses::MEVENT;
+///
+/// let mevent = MEVENT { id: 0, x: 4, y: 2, z: 0, bstate: BUTTON4_PRESSED | BUTTON_SHIFT };
+/// let event = MouseEvent::from(mevent);
+/// assert_eq!(MouseEventKind::ScrollUp, event.kind);
+/// assert!(event.scroll_up && !event.scroll_down);
+/// assert!(event.shift && !event.ctrl && !event.alt);
+/// assert_eq!((2, 4), (event.y, event.x));
+///
+/// let click = MouseEvent::from(MEVENT { bstate: BUTTON1_CLICKED, ..mevent });
+/// assert_eq!((Some(1), MouseEventKind::Click), (click.button, click.kind));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct MouseEvent {
+    /// The device the event came from, for telling several mice apart.
+    pub id: i16,
+    pub y: i32,
+    pub x: i32,
+    /// The button the event is about, from 1 to 5, or None for a Move.
+    pub button: Option<u8>,
+    pub kind: MouseEventKind,
+    /// The raw bit mask of BUTTON constants the event was decoded from.
+    pub bstate: mmask_t,
+    /// Shorthand for a kind of ScrollUp.
+    pub scroll_up: bool,
+    /// Shorthand for a kind of ScrollDown.
+    pub scroll_down: bool,
+    pub shift: bool,
+    pub ctrl: bool,
+    pub alt: bool,
+}
+
+impl MouseEvent {
+    /// Creates an event from device 0 with no modifiers held, for instance to push back with
+    /// `ungetmouse()`. The button is ignored for ScrollUp, ScrollDown and Move, and a Press of
+    /// button 4 or 5 is a scroll, as it would be decoded.
+    pub fn new(y: i32, x: i32, button: Option<u8>, kind: MouseEventKind) -> MouseEvent {
+        let (button, kind) = match (kind, button) {
+            (MouseEventKind::ScrollUp, _) | (MouseEventKind::Press, Some(4)) => {
+                (Some(4), MouseEventKind::ScrollUp)
+            }
+            (MouseEventKind::ScrollDown, _) | (MouseEventKind::Press, Some(5)) => {
+                (Some(5), MouseEventKind::ScrollDown)
+            }
+            (MouseEventKind::Move, _) => (None, MouseEventKind::Move),
+            _ => (button, kind),
+        };
+        let mut event = MouseEvent {
+            id: 0,
+            y,
+            x,
+            button,
+            kind,
+            bstate: 0,
+            scroll_up: kind == MouseEventKind::ScrollUp,
+            scroll_down: kind == MouseEventKind::ScrollDown,
+            shift: false,
+            ctrl: false,
+            alt: false,
+        };
+        event.bstate = event.to_bstate();
+        event
+    }
+
+    /// Encodes the decoded fields back into a bstate bit mask, ignoring the bstate field itself.
+    pub(crate) fn to_bstate(self) -> mmask_t {
+        let event = match self.kind {
+            MouseEventKind::Press | MouseEventKind::ScrollUp | MouseEventKind::ScrollDown => {
+                PRESSED
+            }
+            MouseEventKind::Release => RELEASED,
+            MouseEventKind::Click => CLICKED,
+            MouseEventKind::DoubleClick => DOUBLE_CLICKED,
+            MouseEventKind::TripleClick => TRIPLE_CLICKED,
+            MouseEventKind::Move => return REPORT_MOUSE_POSITION | self.modifier_bits(),
+        };
+        let buttons = match self.button {
+            Some(button) => MouseMask::new().with_button_event(button, event),
+            None => MouseMask::new(),
+        };
+        buttons.bits() | self.modifier_bits()
+    }
+
+    fn modifier_bits(self) -> mmask_t {
+        let mut bits = 0;
+        if self.shift {
+            bits |= BUTTON_SHIFT;
+        }
+        if self.ctrl {
+            bits |= BUTTON_CTRL;
+        }
+        if self.alt {
+            bits |= BUTTON_ALT;
+        }
+        bits
+    }
+}
+
+/// Encodes a MouseEvent from its decoded fields, as `ungetmouse()` does.
+impl From<MouseEvent> for MEVENT {
+    fn from(event: MouseEvent) -> MEVENT {
+        MEVENT {
+            id: event.id,
+            x: event.x,
+            y: event.y,
+            z: 0,
+            bstate: event.to_bstate(),
+        }
+    }
+}
+
+impl From<MEVENT> for MouseEvent {
+    fn from(mevent: MEVENT) -> MouseEvent {
+        let bstate = mevent.bstate;
+        let (button, kind) = decode_buttons(bstate);
+        MouseEvent {
+            id: mevent.id,
+            y: mevent.y,
+            x: mevent.x,
+            button,
+            kind,
+            bstate,
+            scroll_up: kind == MouseEventKind::ScrollUp,
+            scroll_down: kind == MouseEventKind::ScrollDown,
+            shift: bstate & BUTTON_SHIFT != 0,
+            ctrl: bstate & BUTTON_CTRL != 0,
+            alt: bstate & BUTTON_ALT != 0,
+        }
+    }
+}
+
+fn decode_buttons(bstate: mmask_t) -> (Option<u8>, MouseEventKind) {
+    if bstate & REPORT_MOUSE_POSITION == 0 {
+        for (index, masks) in BUTTON_MASKS.iter().enumerate() {
+            let button = index as u8 + 1;
+            let kind = match masks.iter().position(|&mask| bstate & mask != 0) {
+                Some(PRESSED) if button == 4 => MouseEventKind::ScrollUp,
+                Some(PRESSED) if button == 5 => MouseEventKind::ScrollDown,
+                Some(PRESSED) => MouseEventKind::Press,
+                Some(RELEASED) => MouseEventKind::Release,
+                Some(CLICKED) => MouseEventKind::Click,
+                Some(DOUBLE_CLICKED) => MouseEventKind::DoubleClick,
+                Some(_) => MouseEventKind::TripleClick,
+                None => continue,
+            };
+            return (Some(button), kind);
+        }
+    }
+    (None, MouseEventKind::Move)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decode(bstate: mmask_t) -> MouseEvent {
+        MouseEvent::from(MEVENT {
+            id: 1,
+            x: 10,
+            y: 5,
+            z: 0,
+            bstate,
+        })
+    }
+
+    #[test]
+    fn test_buttons_are_decoded() {
+        let cases = [
+            (BUTTON1_PRESSED, Some(1), MouseEventKind::Press),
+            (BUTTON1_RELEASED, Some(1), MouseEventKind::Release),
+            (BUTTON2_CLICKED, Some(2), MouseEventKind::Click),
+            (BUTTON3_DOUBLE_CLICKED, Some(3), MouseEventKind::DoubleClick),
+            (BUTTON1_TRIPLE_CLICKED, Some(1), MouseEventKind::TripleClick),
+            (BUTTON4_PRESSED, Some(4), MouseEventKind::ScrollUp),
+            (BUTTON5_PRESSED, Some(5), MouseEventKind::ScrollDown),
+            (BUTTON5_RELEASED, Some(5), MouseEventKind::Release),
+            (REPORT_MOUSE_POSITION, None, MouseEventKind::Move),
+            (0, None, MouseEventKind::Move),
+        ];
+        for &(bstate, button, kind) in cases.iter() {
+            let event = decode(bstate);
+            assert_eq!((button, kind), (event.button, event.kind), "bstate {:#x}", bstate);
+            assert_eq!((1, 5, 10), (event.id, event.y, event.x));
+        }
+    }
+
+    #[test]
+    fn test_mask_builds_on_backend_constants() {
+        let mask = MouseMask::new().button_press(1).button_double_click(3);
+        assert_eq!(BUTTON1_PRESSED | BUTTON3_DOUBLE_CLICKED, mask.bits());
+        assert_eq!(BUTTON4_PRESSED | BUTTON5_PRESSED, MouseMask::new().with_wheel().bits());
+        assert_eq!(MouseMask::new(), MouseMask::new().button_press(0).button_click(6));
+        assert!(MouseMask::all_buttons().contains(MouseMask::from(DEFAULT_MOUSE_EVENTS)));
+        assert!(!MouseMask::all_buttons().contains(MouseMask::new().with_motion()));
+    }
+
+    #[test]
+    fn test_events_are_encoded() {
+        let kinds = [
+            MouseEventKind::Press,
+            MouseEventKind::Release,
+            MouseEventKind::Click,
+            MouseEventKind::DoubleClick,
+            MouseEventKind::TripleClick,
+        ];
+        for button in 1..=5 {
+            for &kind in kinds.iter() {
+                let event = MouseEvent::new(5, 10, Some(button), kind);
+                assert_eq!(event, MouseEvent::from(MEVENT::from(event)));
+            }
+        }
+
+        let mut drag = MouseEvent::new(3, 4, None, MouseEventKind::Move);
+        drag.ctrl = true;
+        let encoded = MEVENT::from(drag);
+        assert_eq!(REPORT_MOUSE_POSITION | BUTTON_CTRL, encoded.bstate);
+        assert_eq!(MouseEventKind::Move, MouseEvent::from(encoded).kind);
+
+        let wheel = MouseEvent::new(0, 0, None, MouseEventKind::ScrollDown);
+        assert_eq!((Some(5), BUTTON5_PRESSED), (wheel.button, wheel.bstate));
+    }
+
+    #[test]
+    fn test_modifiers_are_decoded() {
+        let event = decode(BUTTON1_CLICKED | BUTTON_CTRL | BUTTON_ALT);
+        assert_eq!(MouseEventKind::Click, event.kind);
+        assert!(event.ctrl && event.alt && !event.shift);
+
+        let event = decode(BUTTON5_PRESSED | BUTTON_SHIFT);
+        assert!(event.scroll_down && !event.scroll_up && event.shift);
+    }
+}
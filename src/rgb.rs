@@ -0,0 +1,185 @@
+/// A color given as red, green and blue components from 0 to 255, as used by most other
+/// software, for `init_color_rgb()` and `color_content_rgb()`.
+///
+/// Curses works in components from 0 to 1000. Converting to that scale and back gives the
+/// original value, so a color set with `init_color_rgb()` reads back unchanged.
+///
+/// # Example
+///
+/// ```
+/// use pancurses::Rgb;
+///
+/// assert_eq!(Some(Rgb::new(0xff, 0x80, 0x00)), Rgb::from_hex("#ff8000"));
+/// assert_eq!(None, Rgb::from_hex("#ff80"));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Rgb {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Rgb {
+    /// Creates an Rgb from its components.
+    pub const fn new(r: u8, g: u8, b: u8) -> Rgb {
+        Rgb { r, g, b }
+    }
+
+    /// Parses a color written as "#rrggbb", in upper or lower case. The leading '#' may be left
+    /// out. Returns None if the string isn't six hexadecimal digits.
+    pub fn from_hex(hex: &str) -> Option<Rgb> {
+        let digits = hex.strip_prefix('#').unwrap_or(hex);
+        if digits.len() != 6 || !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return None;
+        }
+        let component = |i: usize| u8::from_str_radix(&digits[i..i + 2], 16).ok();
+        Some(Rgb::new(component(0)?, component(2)?, component(4)?))
+    }
+
+    /// Converts curses components, from 0 to 1000, to the nearest Rgb. Values outside that range
+    /// are clamped.
+    pub(crate) fn from_curses(red: i16, green: i16, blue: i16) -> Rgb {
+        Rgb::new(
+            from_curses_component(red),
+            from_curses_component(green),
+            from_curses_component(blue),
+        )
+    }
+
+    /// Converts to curses components, from 0 to 1000.
+    pub(crate) fn to_curses(self) -> (i16, i16, i16) {
+        (
+            to_curses_component(self.r),
+            to_curses_component(self.g),
+            to_curses_component(self.b),
+        )
+    }
+}
+
+/// The component levels of the 6x6x6 color cube in the xterm 256 color palette, colors 16 to 231.
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// Returns the entry of the xterm 256 color palette closest to an Rgb, by the squared distance
+/// between their components. Only the 6x6x6 color cube and the grayscale ramp, colors 16 to
+/// 255, are considered, since colors 0 to 15 usually follow the terminal's theme. When two
+/// entries are equally close the lower numbered one is returned.
+///
+/// # Example
+///
+/// ```
+/// use pancurses::{nearest_color, Rgb};
+///
+/// assert_eq!(196, nearest_color(Rgb::new(255, 0, 0)));
+/// assert_eq!(244, nearest_color(Rgb::new(128, 128, 128)));
+/// ```
+pub fn nearest_color(rgb: Rgb) -> i16 {
+    // min_by_key() returns the first of equally close candidates, so ties go to the lower number
+    let cube_index = |value: u8| {
+        (0..6)
+            .min_by_key(|&index| (i32::from(CUBE_LEVELS[index]) - i32::from(value)).abs())
+            .unwrap_or(0)
+    };
+    let (r, g, b) = (cube_index(rgb.r), cube_index(rgb.g), cube_index(rgb.b));
+    let cube = Rgb::new(CUBE_LEVELS[r], CUBE_LEVELS[g], CUBE_LEVELS[b]);
+    let gray_step = (0..24).min_by_key(|&step| distance(rgb, gray_level(step))).unwrap_or(0);
+
+    if distance(rgb, cube) <= distance(rgb, gray_level(gray_step)) {
+        (16 + 36 * r + 6 * g + b) as i16
+    } else {
+        232 + i16::from(gray_step)
+    }
+}
+
+/// Returns the gray of step 0 to 23 of the grayscale ramp, colors 232 to 255.
+fn gray_level(step: u8) -> Rgb {
+    let level = 8 + 10 * step;
+    Rgb::new(level, level, level)
+}
+
+fn distance(a: Rgb, b: Rgb) -> u32 {
+    let square = |x: u8, y: u8| {
+        let difference = i32::from(x) - i32::from(y);
+        (difference * difference) as u32
+    };
+    square(a.r, b.r) + square(a.g, b.g) + square(a.b, b.b)
+}
+
+// Both conversions round to nearest. A step of 255ths is almost four times as wide as a step of
+// 1000ths, so the rounding error on the way to 1000ths can never carry a value to a different
+// 255th on the way back.
+fn to_curses_component(value: u8) -> i16 {
+    ((i32::from(value) * 1000 + 127) / 255) as i16
+}
+
+fn from_curses_component(value: i16) -> u8 {
+    let value = i32::from(value).clamp(0, 1000);
+    ((value * 255 + 500) / 1000) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_components_round_trip() {
+        for value in 0..=255u8 {
+            let rgb = Rgb::new(value, 255 - value, value / 2);
+            let (r, g, b) = rgb.to_curses();
+            assert!((0..=1000).contains(&r));
+            assert_eq!(rgb, Rgb::from_curses(r, g, b));
+        }
+        assert_eq!((0, 1000, 502), Rgb::new(0, 255, 128).to_curses());
+        assert_eq!(Rgb::new(0, 255, 255), Rgb::from_curses(-1, 1000, 1001));
+    }
+
+    #[test]
+    fn test_nearest_color_corners() {
+        assert_eq!(16, nearest_color(Rgb::new(0, 0, 0)));
+        assert_eq!(231, nearest_color(Rgb::new(255, 255, 255)));
+        assert_eq!(46, nearest_color(Rgb::new(0, 255, 0)));
+        assert_eq!(21, nearest_color(Rgb::new(0, 0, 255)));
+        assert_eq!(226, nearest_color(Rgb::new(255, 255, 0)));
+        // Every cube entry matches itself exactly
+        let mut color = 16;
+        for &r in &CUBE_LEVELS {
+            for &g in &CUBE_LEVELS {
+                for &b in &CUBE_LEVELS {
+                    assert_eq!(color, nearest_color(Rgb::new(r, g, b)));
+                    color += 1;
+                }
+            }
+        }
+        assert_eq!(202, nearest_color(Rgb::from_hex("#ff6010").unwrap()));
+    }
+
+    #[test]
+    fn test_nearest_color_grays() {
+        for step in 0..24 {
+            assert_eq!(232 + i16::from(step), nearest_color(gray_level(step)));
+        }
+        assert_eq!(232, nearest_color(Rgb::new(5, 5, 5)));
+        assert_eq!(255, nearest_color(Rgb::new(240, 240, 240)));
+        // Grays the cube has exactly are taken from the cube
+        assert_eq!(59, nearest_color(Rgb::new(95, 95, 95)));
+    }
+
+    #[test]
+    fn test_nearest_color_ties() {
+        // 115 lies halfway between the cube levels 95 and 135
+        assert_eq!(52, nearest_color(Rgb::new(115, 0, 0)));
+        // 13 lies halfway between the grays 8 and 18
+        assert_eq!(232, nearest_color(Rgb::new(13, 13, 13)));
+        // Black of the cube and the first gray are equally far from 4
+        assert_eq!(16, nearest_color(Rgb::new(4, 4, 4)));
+    }
+
+    #[test]
+    fn test_hex_parsing() {
+        assert_eq!(Some(Rgb::new(0x12, 0xab, 0xCD)), Rgb::from_hex("12AbcD"));
+        assert_eq!(None, Rgb::from_hex(""));
+        assert_eq!(None, Rgb::from_hex("#12345g"));
+        assert_eq!(None, Rgb::from_hex("#+12345"));
+        assert_eq!(None, Rgb::from_hex("#1234567"));
+        assert_eq!(None, Rgb::from_hex("##123456"));
+    }
+}
@@ -0,0 +1,104 @@
+use std::fmt;
+use super::{chtype, A_ATTRIBUTES, A_CHARTEXT, A_COLOR, PAIR_NUMBER};
+use crate::attributes::Attributes;
+use crate::window::acs_to_unicode;
+
+/// A single character cell of a window, decomposed into its character, attributes and color
+/// pair.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Cell {
+    pub ch: char,
+    pub attributes: Attributes,
+    pub color_pair: i16,
+}
+
+/// Splits a chtype, as returned by `mvinch()`, into its parts. Characters that aren't valid
+/// Unicode scalar values are replaced by U+FFFD.
+///
+/// # Example
+///
+/// ```
+/// use pancurses::{Attribute, Cell, A_BOLD, COLOR_PAIR};
+///
+/// let cell = Cell::from('x' as u32 | A_BOLD | COLOR_PAIR(2));
+/// assert_eq!('x', cell.ch);
+/// assert!(cell.attributes.is_bold());
+/// assert_eq!(2, cell.color_pair);
+/// ```
+impl From<chtype> for Cell {
+    // chtype is already a u32 on ncurses but not on PDCurses
+    #[cfg_attr(unix, allow(clippy::unnecessary_cast))]
+    fn from(ch: chtype) -> Cell {
+        Cell {
+            ch: std::char::from_u32((ch & A_CHARTEXT) as u32).unwrap_or('\u{fffd}'),
+            attributes: Attributes::from(ch & A_ATTRIBUTES & !A_COLOR),
+            color_pair: PAIR_NUMBER(ch) as i16,
+        }
+    }
+}
+
+/// A copy of the contents of a window, taken with `Window::snapshot()`.
+///
+/// Displaying a ScreenBuffer renders its text as a plain grid, one line per row, which makes
+/// for readable assertion failures in tests.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScreenBuffer {
+    rows: Vec<Vec<Cell>>,
+}
+
+impl ScreenBuffer {
+    pub(crate) fn new(rows: Vec<Vec<Cell>>) -> ScreenBuffer {
+        ScreenBuffer { rows }
+    }
+
+    /// Returns the cell at the given position, or None if it's outside the buffer.
+    pub fn cell(&self, y: usize, x: usize) -> Option<&Cell> {
+        self.rows.get(y).and_then(|row| row.get(x))
+    }
+
+    /// Returns the number of rows in the buffer.
+    pub fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Returns the text of row y with trailing blanks stripped, or an empty string if the row is
+    /// outside the buffer. Line drawing characters from the alternate character set, such as
+    /// those of draw_box(), are given as the Unicode box-drawing characters they stand for.
+    pub fn line(&self, y: usize) -> String {
+        let text = |cell: &Cell| {
+            if cell.attributes.is_alternative_char_set() {
+                acs_to_unicode(cell.ch)
+            } else {
+                cell.ch
+            }
+        };
+        let line: String = self
+            .rows
+            .get(y)
+            .map(|row| row.iter().map(text).collect())
+            .unwrap_or_default();
+        line.trim_end_matches(' ').to_string()
+    }
+
+    /// Returns the rows of cells in the buffer.
+    pub fn rows(&self) -> &[Vec<Cell>] {
+        &self.rows
+    }
+
+    /// Returns the number of columns in the buffer.
+    pub fn width(&self) -> usize {
+        self.rows.first().map_or(0, |row| row.len())
+    }
+}
+
+impl fmt::Display for ScreenBuffer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for y in 0..self.height() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}", self.line(y))?;
+        }
+        Ok(())
+    }
+}
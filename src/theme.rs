@@ -0,0 +1,205 @@
+use std::collections::HashMap;
+use crate::attributes::Attributes;
+use crate::color::Color;
+use crate::colorpair::ColorPairs;
+use crate::error::ThemeError;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Named styles, each an Attributes value with a color pair already set up, so that the rest of a
+/// program can ask for "status_bar" rather than remember which pair number that is.
+///
+/// Pairs are allocated with the `ColorPairs` registry the theme is built with, so styles with the
+/// same colors share one, and pairs the rest of the program got from the same registry aren't
+/// redefined.
+///
+/// # Example
+///
+/// ```
+/// use pancurses::{endwin, has_colors, initscr, start_color, Attribute, Color, ColorPair};
+/// use pancurses::{ColorPairs, Theme, COLOR_BLACK, COLOR_GREEN};
+///
+/// let window = initscr();
+/// if has_colors() {
+///     start_color();
+/// }
+/// let mut pairs = ColorPairs::new();
+/// let prompt = pairs.init_pair(1, COLOR_GREEN, COLOR_BLACK);
+/// let theme = Theme::builder()
+///     .style("status_bar", Color::White, Color::Blue, Attribute::Bold)
+///     .style("error", Color::Red, Color::Default, Attribute::Normal)
+///     .build(&mut pairs);
+/// if let Ok(theme) = theme {
+///     window.attrset(theme.get("status_bar"));
+///     window.printw("Ready");
+///     assert!(theme.get("status_bar").is_bold());
+///     // Pair 1 was already taken, so the theme left it alone
+///     assert_eq!(Ok(ColorPair(1)), prompt);
+///     assert_eq!(ColorPair(2), theme.get("status_bar").color_pair());
+/// }
+/// endwin();
+/// ```
+#[derive(Clone, Debug)]
+pub struct Theme {
+    styles: Vec<Style>,
+    attributes: HashMap<String, Attributes>,
+}
+
+impl Theme {
+    /// Returns a builder to add the styles of a theme to.
+    pub fn builder() -> ThemeBuilder {
+        ThemeBuilder::default()
+    }
+
+    /// Returns the attributes, including the color pair, of the named style. Styles the theme
+    /// doesn't have come back as plain `Attributes::new()`.
+    pub fn get(&self, name: &str) -> Attributes {
+        self.attributes.get(name).copied().unwrap_or_default()
+    }
+
+    /// Allocates and initializes the color pairs of the theme afresh with the given registry.
+    /// Call this after calling start_color() again, for instance when curses has been set up
+    /// anew with initscr(), since the pair definitions don't survive that. The registry the
+    /// theme was built with doesn't either, so pass a new one.
+    pub fn reapply(&mut self, pairs: &mut ColorPairs) -> Result<(), ThemeError> {
+        let mut attributes = HashMap::with_capacity(self.styles.len());
+        for style in &self.styles {
+            let pair = pairs.get_or_init(style.foreground.into(), style.background.into())?;
+            let mut style_attributes = style.attributes;
+            style_attributes.set_color_pair(pair);
+            attributes.insert(style.name.clone(), style_attributes);
+        }
+        self.attributes = attributes;
+        Ok(())
+    }
+
+    /// Returns the builder this theme was built with, for instance to add some styles to it.
+    pub fn to_builder(&self) -> ThemeBuilder {
+        ThemeBuilder {
+            styles: self.styles.clone(),
+        }
+    }
+}
+
+/// Collects the styles of a `Theme`, returned by `Theme::builder()`.
+///
+/// With the `serde` feature a ThemeBuilder can be serialized, so a theme can be loaded from a
+/// file and then built. It's stored as a list of styles, each with its name, foreground and
+/// background colors, and attributes.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(transparent))]
+pub struct ThemeBuilder {
+    styles: Vec<Style>,
+}
+
+impl ThemeBuilder {
+    /// Adds a style drawn with the given colors and attributes.
+    pub fn style<A: Into<Attributes>>(
+        mut self,
+        name: &str,
+        foreground: Color,
+        background: Color,
+        attributes: A,
+    ) -> ThemeBuilder {
+        self.styles.push(Style {
+            name: name.to_string(),
+            foreground,
+            background,
+            attributes: attributes.into(),
+        });
+        self
+    }
+
+    /// Allocates and initializes a color pair for each combination of colors the styles use,
+    /// taking them from the given registry. Call start_color() first.
+    ///
+    /// Fails with DuplicateStyle if two styles have the same name, or with the ColorError a pair
+    /// couldn't be set up with, such as PairsExhausted if there are more combinations than color
+    /// pairs.
+    pub fn build(self, pairs: &mut ColorPairs) -> Result<Theme, ThemeError> {
+        for (index, style) in self.styles.iter().enumerate() {
+            if self.styles[..index].iter().any(|other| other.name == style.name) {
+                return Err(ThemeError::DuplicateStyle(style.name.clone()));
+            }
+        }
+        let mut theme = Theme {
+            styles: self.styles,
+            attributes: HashMap::new(),
+        };
+        theme.reapply(pairs)?;
+        Ok(theme)
+    }
+}
+
+/// One style of a theme, as given to `ThemeBuilder::style()`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+struct Style {
+    name: String,
+    foreground: Color,
+    background: Color,
+    #[cfg_attr(feature = "serde", serde(default))]
+    attributes: Attributes,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::attributes::Attribute;
+
+    #[test]
+    fn test_duplicate_styles_are_rejected() {
+        let theme = Theme::builder()
+            .style("title", Color::White, Color::Blue, Attribute::Bold)
+            .style("title", Color::Black, Color::White, Attribute::Normal)
+            .build(&mut ColorPairs::new());
+        assert_eq!(
+            Some(ThemeError::DuplicateStyle("title".to_string())),
+            theme.err()
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_theme_builder() {
+        use serde_test::{assert_tokens, Token};
+
+        assert_tokens(
+            &Theme::builder().style("error", Color::Red, Color::Number(-1), Attribute::Normal),
+            &[
+                Token::Seq { len: Some(1) },
+                Token::Struct {
+                    name: "Style",
+                    len: 4,
+                },
+                Token::Str("name"),
+                Token::Str("error"),
+                Token::Str("foreground"),
+                Token::UnitVariant {
+                    name: "Color",
+                    variant: "red",
+                },
+                Token::Str("background"),
+                Token::NewtypeVariant {
+                    name: "Color",
+                    variant: "number",
+                },
+                Token::I16(-1),
+                Token::Str("attributes"),
+                Token::Struct {
+                    name: "SerializedAttributes",
+                    len: 2,
+                },
+                Token::Str("attributes"),
+                Token::Seq { len: Some(0) },
+                Token::SeqEnd,
+                Token::Str("color_pair"),
+                Token::None,
+                Token::StructEnd,
+                Token::StructEnd,
+                Token::SeqEnd,
+            ],
+        );
+    }
+}
@@ -53,6 +53,10 @@ pub fn COLOR_PAIR(n: chtype) -> attr_t {
     ncurses::COLOR_PAIR(n as i16)
 }
 
+pub fn PAIR_NUMBER(n: chtype) -> chtype {
+    ncurses::PAIR_NUMBER(n as i32) as chtype
+}
+
 pub use ncurses::COLOR_BLACK;
 pub use ncurses::COLOR_BLUE;
 pub use ncurses::COLOR_CYAN;
@@ -62,27 +66,33 @@ pub use ncurses::COLOR_RED;
 pub use ncurses::COLOR_WHITE;
 pub use ncurses::COLOR_YELLOW;
 
-pub const A_ALTCHARSET: attr_t = ncurses::A_ALTCHARSET;
-pub const A_ATTRIBUTES: attr_t = ncurses::A_ATTRIBUTES;
-pub const A_BLINK: attr_t = ncurses::A_BLINK;
-pub const A_BOLD: attr_t = ncurses::A_BOLD;
-pub const A_CHARTEXT: attr_t = ncurses::A_CHARTEXT;
-pub const A_COLOR: attr_t = ncurses::A_COLOR;
-pub const A_DIM: attr_t = ncurses::A_DIM;
+pub const A_ALTCHARSET: attr_t = ncurses::A_ALTCHARSET();
+pub const A_ATTRIBUTES: attr_t = ncurses::A_ATTRIBUTES();
+pub const A_BLINK: attr_t = ncurses::A_BLINK();
+pub const A_BOLD: attr_t = ncurses::A_BOLD();
+pub const A_CHARTEXT: attr_t = ncurses::A_CHARTEXT();
+pub const A_COLOR: attr_t = ncurses::A_COLOR();
+pub const A_DIM: attr_t = ncurses::A_DIM();
+pub const A_HORIZONTAL: attr_t = ncurses::A_HORIZONTAL();
 pub const A_ITALIC: attr_t = 0;
-pub const A_INVIS: attr_t = ncurses::A_INVIS;
+pub const A_INVIS: attr_t = ncurses::A_INVIS();
 pub const A_LEFTLINE: attr_t = 0; // Not supported on ncurses
-pub const A_NORMAL: attr_t = ncurses::A_NORMAL;
+pub const A_LOW: attr_t = ncurses::A_LOW();
+pub const A_NORMAL: attr_t = ncurses::A_NORMAL();
 pub const A_OVERLINE: attr_t = 0; // Not supported on ncurses
-pub const A_REVERSE: attr_t = ncurses::A_REVERSE;
+pub const A_PROTECT: attr_t = ncurses::A_PROTECT();
+pub const A_REVERSE: attr_t = ncurses::A_REVERSE();
 pub const A_RIGHTLINE: attr_t = 0; // Not supported on ncurses
-pub const A_STANDOUT: attr_t = ncurses::A_STANDOUT;
+pub const A_STANDOUT: attr_t = ncurses::A_STANDOUT();
 pub const A_STRIKEOUT: attr_t = 0; // Not supported on ncurses
-pub const A_UNDERLINE: attr_t = ncurses::A_UNDERLINE;
+pub const A_TOP: attr_t = ncurses::A_TOP();
+pub const A_UNDERLINE: attr_t = ncurses::A_UNDERLINE();
+pub const A_VERTICAL: attr_t = ncurses::A_VERTICAL();
 
 pub const KEY_OFFSET: i32 = 0o0400;
 pub const KEY_RESIZE: i32 = ncurses::KEY_RESIZE;
 pub const KEY_F15: i32 = ncurses::KEY_F0 + 15;
+pub const KEY_F63: i32 = ncurses::KEY_F0 + 63;
 pub const KEY_EVENT: i32 = 0;
 
 pub const SPECIAL_KEY_CODES: [Input; 108] = [
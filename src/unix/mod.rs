@@ -2,21 +2,147 @@
 pub mod constants;
 use self::constants::*;
 
-use ncurses::{box_, getmouse, keyname, COLORS, COLOR_PAIRS};
-use ncurses::ll::{chtype, ungetch, wattroff, wattron, wattrset, MEVENT, NCURSES_ATTR_T, WINDOW};
-use ncurses::ll::{resize_term, wgetch};
+use ncurses::{box_, getmouse, keyname, COLORS, COLOR_PAIRS, COLS, LINES};
+use ncurses::ll::{attr_t, chtype, ungetch, wattroff, wattron, wattrset, MEVENT, NCURSES_ATTR_T, WINDOW};
+use ncurses::ll::{wattr_off, wattr_on, wattr_set, wcolor_set};
+use ncurses::ll::{getcurx, getmaxx};
+use ncurses::ll::{is_term_resized, resize_term, resizeterm, set_escdelay, wgetch, wtimeout};
+use ncurses::ll::{tigetflag, tigetnum, tigetstr};
+#[cfg(not(feature = "wide"))]
+use ncurses::ll::{waddch, waddnstr, wgetnstr, winnstr};
 
-use libc::{c_int, setlocale, LC_ALL};
+use libc::{c_char, c_int, c_short, c_void, setlocale, LC_ALL};
+#[cfg(feature = "wide")]
+use libc::wchar_t;
+#[cfg(feature = "wide")]
+use ncurses::ll::{unget_wch, wget_wch, winttype};
+#[cfg(feature = "wide")]
+use ncurses::KEY_CODE_YES;
+#[cfg(not(feature = "wide"))]
+use ncurses::ll::mvwinch;
+#[cfg(feature = "wide")]
+use crate::attributes::Attributes;
+#[cfg(feature = "wide")]
+use crate::colorpair::ColorPair;
+use crate::complexchar::ComplexChar;
 use crate::input::Input;
+use crate::keyevent::KeyEvent;
+use crate::screenbuffer::Cell;
 
-use std::ffi::CString;
+use std::convert::TryFrom;
+use std::ffi::{CStr, CString};
+use std::io::{self, Write};
+use std::mem;
+use std::ptr;
 use std::string::FromUtf8Error;
 
+/// Mirrors ncurses' cchar_t, which ncurses-rs doesn't bind.
+#[cfg(feature = "wide")]
+#[repr(C)]
+#[derive(Copy, Clone, Default)]
+pub struct cchar_t {
+    attr: attr_t,
+    chars: [wchar_t; 5],
+    ext_color: c_int,
+}
+
+extern "C" {
+    fn define_key(definition: *const c_char, keycode: c_int) -> c_int;
+    fn get_escdelay() -> c_int;
+    fn has_key(keycode: c_int) -> c_int;
+    fn key_defined(definition: *const c_char) -> c_int;
+    fn wgetdelay(w: WINDOW) -> c_int;
+}
+
+// The key codes the bracketed paste markers are bound to with define_key(), well above the codes
+// ncurses hands out to extended terminfo capabilities
+const KEY_PASTE_START: c_int = 0o7700;
+const KEY_PASTE_END: c_int = 0o7701;
+
+#[cfg(feature = "wide")]
+extern "C" {
+    fn getcchar(
+        wcval: *const cchar_t,
+        wch: *mut wchar_t,
+        attrs: *mut attr_t,
+        color_pair: *mut c_short,
+        opts: *mut c_void,
+    ) -> c_int;
+    fn mvwadd_wch(w: WINDOW, y: c_int, x: c_int, wch: *const cchar_t) -> c_int;
+    fn setcchar(
+        wcval: *mut cchar_t,
+        wch: *const wchar_t,
+        attrs: attr_t,
+        color_pair: c_short,
+        opts: *const c_void,
+    ) -> c_int;
+    fn wadd_wch(w: WINDOW, wch: *const cchar_t) -> c_int;
+    fn waddnwstr(w: WINDOW, wstr: *const wchar_t, n: c_int) -> c_int;
+    fn wgetn_wstr(w: WINDOW, wstr: *mut winttype, n: c_int) -> c_int;
+    fn mvwin_wch(w: WINDOW, y: c_int, x: c_int, wcval: *mut cchar_t) -> c_int;
+    fn winnwstr(w: WINDOW, wstr: *mut wchar_t, n: c_int) -> c_int;
+}
+
 pub fn pre_init() {
     let buf = CString::new("").unwrap();
     unsafe { setlocale(LC_ALL, buf.as_ptr()) };
 }
 
+#[cfg(not(feature = "wide"))]
+pub fn _add_wch(_: WINDOW, _: &ComplexChar) -> i32 {
+    crate::ERR
+}
+
+#[cfg(feature = "wide")]
+pub fn _add_wch(w: WINDOW, ch: &ComplexChar) -> i32 {
+    match to_cchar(ch) {
+        Some(wcval) => unsafe { wadd_wch(w, &wcval) },
+        None => crate::ERR,
+    }
+}
+
+/// The narrow library gives every byte its own cell, so a multi-byte character that would
+/// straddle the end of a line is moved to the next one, and the string is cut short if there's
+/// no line left for it.
+#[cfg(not(feature = "wide"))]
+pub fn _addstr(w: WINDOW, string: &str) -> i32 {
+    let bytes = string.as_bytes();
+    let mut start = 0;
+    for (index, c) in string.char_indices() {
+        if c.is_ascii() {
+            continue;
+        }
+        if add_bytes(w, &bytes[start..index]) == crate::ERR {
+            return crate::ERR;
+        }
+        start = index;
+        let width = c.len_utf8() as i32;
+        let max_x = unsafe { getmaxx(w) };
+        if width > max_x {
+            return crate::ERR;
+        }
+        let overflows = unsafe { getcurx(w) } + width > max_x;
+        if overflows && unsafe { waddch(w, '\n' as chtype) } == crate::ERR {
+            return crate::ERR;
+        }
+    }
+    add_bytes(w, &bytes[start..])
+}
+
+#[cfg(not(feature = "wide"))]
+fn add_bytes(w: WINDOW, bytes: &[u8]) -> i32 {
+    if bytes.is_empty() {
+        return crate::OK;
+    }
+    unsafe { waddnstr(w, bytes.as_ptr() as *const c_char, bytes.len() as c_int) }
+}
+
+#[cfg(feature = "wide")]
+pub fn _addwstr(w: WINDOW, string: &str) -> i32 {
+    let wide: Vec<wchar_t> = string.chars().map(|c| c as wchar_t).collect();
+    unsafe { waddnwstr(w, wide.as_ptr(), wide.len() as c_int) }
+}
+
 pub fn _attron(w: WINDOW, attributes: chtype) -> i32 {
     unsafe { wattron(w, attributes as NCURSES_ATTR_T) }
 }
@@ -29,6 +155,48 @@ pub fn _attrset(w: WINDOW, attributes: chtype) -> i32 {
     unsafe { wattrset(w, attributes as NCURSES_ATTR_T) }
 }
 
+pub fn _attr_on(w: WINDOW, attributes: chtype) -> i32 {
+    unsafe { wattr_on(w, attributes as attr_t, ptr::null()) }
+}
+
+pub fn _attr_off(w: WINDOW, attributes: chtype) -> i32 {
+    unsafe { wattr_off(w, attributes as attr_t, ptr::null()) }
+}
+
+pub fn _attr_set(w: WINDOW, attributes: chtype, color_pair: i16) -> i32 {
+    unsafe { wattr_set(w, attributes as attr_t, color_pair, ptr::null()) }
+}
+
+/// Sets the attributes and color pair through wattr_set(), passing pair numbers that don't fit
+/// in a short the extended way.
+pub fn _attr_set_extended(w: WINDOW, attributes: chtype, color_pair: i32) -> i32 {
+    match extended_pair_opts(color_pair) {
+        Some((short_pair, opts)) => unsafe {
+            wattr_set(w, attributes as attr_t, short_pair, &opts as *const c_int as *const c_void)
+        },
+        None => crate::ERR,
+    }
+}
+
+pub fn _color_set_extended(w: WINDOW, color_pair: i32) -> i32 {
+    match extended_pair_opts(color_pair) {
+        Some((short_pair, opts)) => unsafe {
+            wcolor_set(w, short_pair, &opts as *const c_int as *const c_void)
+        },
+        None => crate::ERR,
+    }
+}
+
+/// Returns the short pair number and the int ncurses 6.1 takes through the opts argument for
+/// a pair that may not fit in a short, or None if this ncurses can't select the pair.
+fn extended_pair_opts(color_pair: i32) -> Option<(c_short, c_int)> {
+    if optional_symbol(b"init_extended_pair\0").is_some() {
+        Some((color_pair.min(c_short::MAX as i32) as c_short, color_pair))
+    } else {
+        c_short::try_from(color_pair).ok().map(|short_pair| (short_pair, 0))
+    }
+}
+
 pub fn _COLORS() -> i32 {
     COLORS()
 }
@@ -57,24 +225,383 @@ pub fn _getmouse() -> Result<MEVENT, i32> {
     }
 }
 
+pub fn _get_key_event(w: WINDOW) -> Option<KeyEvent> {
+    match _wgetch(w)? {
+        Input::Character('\u{1b}') => {
+            // Alt is sent as an ESC prefix, so see whether anything follows right away
+            let delay = _getdelay(w);
+            unsafe { wtimeout(w, 0) };
+            let next = _wgetch(w);
+            unsafe { wtimeout(w, delay) };
+            Some(match next {
+                Some(key) => KeyEvent {
+                    alt: true,
+                    ..modified_key(key)
+                },
+                None => KeyEvent::new(Input::Character('\u{1b}')),
+            })
+        }
+        key => Some(modified_key(key)),
+    }
+}
+
+/// Recognizes the modifiers in a key read by getch(): the shifted keys curses has codes for, and
+/// the codes ncurses assigns to extended terminfo capabilities like kLFT5 for Ctrl+Left.
+fn modified_key(key: Input) -> KeyEvent {
+    match key {
+        Input::Unknown(code) => keyname(code)
+            .and_then(|name| KeyEvent::from_extended_name(&name))
+            .unwrap_or_else(|| KeyEvent::new(key)),
+        other => KeyEvent::from_shifted(other),
+    }
+}
+
+pub fn _get_escdelay() -> i32 {
+    unsafe { get_escdelay() }
+}
+
+/// Returns the window's input delay in the terms timeout() takes it.
+pub fn _getdelay(w: WINDOW) -> i32 {
+    unsafe { wgetdelay(w) }
+}
+
+#[cfg(not(feature = "wide"))]
+pub fn _getnstr(w: WINDOW, max: usize) -> Result<String, i32> {
+    let n = max.min(c_int::MAX as usize - 1);
+    let mut buffer = vec![0u8; n + 1];
+    if unsafe { wgetnstr(w, buffer.as_mut_ptr(), n as c_int) } == crate::ERR {
+        return Err(crate::ERR);
+    }
+    let length = buffer.iter().position(|&b| b == 0).unwrap_or(n);
+    buffer.truncate(length);
+    Ok(String::from_utf8_lossy(&buffer).into_owned())
+}
+
+#[cfg(feature = "wide")]
+pub fn _getnstr(w: WINDOW, max: usize) -> Result<String, i32> {
+    let n = max.min(c_int::MAX as usize - 1);
+    let mut buffer: Vec<winttype> = vec![0; n + 1];
+    if unsafe { wgetn_wstr(w, buffer.as_mut_ptr(), n as c_int) } == crate::ERR {
+        return Err(crate::ERR);
+    }
+    Ok(buffer
+        .iter()
+        .take_while(|&&c| c != 0)
+        .map(|&c| std::char::from_u32(c).unwrap_or('\u{fffd}'))
+        .collect())
+}
+
+#[cfg(not(feature = "wide"))]
+pub fn _innstr(w: WINDOW, n: i32) -> String {
+    let n = clamp_to_line(w, n);
+    let mut buffer = vec![0u8; n as usize + 1];
+    let length = unsafe { winnstr(w, buffer.as_mut_ptr() as *mut _, n) };
+    buffer.truncate(length.max(0) as usize);
+    String::from_utf8_lossy(&buffer).into_owned()
+}
+
+#[cfg(feature = "wide")]
+pub fn _innstr(w: WINDOW, n: i32) -> String {
+    let n = clamp_to_line(w, n);
+    let mut buffer: Vec<wchar_t> = vec![0; n as usize + 1];
+    let length = unsafe { winnwstr(w, buffer.as_mut_ptr(), n) };
+    buffer.truncate(length.max(0) as usize);
+    buffer
+        .iter()
+        .filter_map(|&c| std::char::from_u32(c as u32))
+        .collect()
+}
+
+/// Curses reads to the end of the line for a negative n, so that has to be what the buffer is
+/// sized for.
+fn clamp_to_line(w: WINDOW, n: i32) -> i32 {
+    if n < 0 {
+        unsafe { getmaxx(w) - getcurx(w) }.max(0)
+    } else {
+        n
+    }
+}
+
+#[cfg(not(feature = "wide"))]
+pub fn _mvadd_wch(_: WINDOW, _: i32, _: i32, _: &ComplexChar) -> i32 {
+    crate::ERR
+}
+
+#[cfg(feature = "wide")]
+pub fn _mvadd_wch(w: WINDOW, y: i32, x: i32, ch: &ComplexChar) -> i32 {
+    match to_cchar(ch) {
+        Some(wcval) => unsafe { mvwadd_wch(w, y, x, &wcval) },
+        None => crate::ERR,
+    }
+}
+
+#[cfg(not(feature = "wide"))]
+pub fn _mvin_cell(w: WINDOW, y: i32, x: i32) -> Option<Cell> {
+    let ch = unsafe { mvwinch(w, y, x) };
+    if ch == crate::ERR as chtype {
+        None
+    } else {
+        Some(Cell::from(ch))
+    }
+}
+
+#[cfg(feature = "wide")]
+pub fn _mvin_cell(w: WINDOW, y: i32, x: i32) -> Option<Cell> {
+    let (chars, attrs, color_pair) = mvin_cchar(w, y, x)?;
+    Some(Cell {
+        ch: chars.first().copied().unwrap_or(' '),
+        attributes: Attributes::from(attrs as chtype & !A_COLOR),
+        color_pair,
+    })
+}
+
+#[cfg(not(feature = "wide"))]
+pub fn _mvin_wch(w: WINDOW, y: i32, x: i32) -> Option<ComplexChar> {
+    _mvin_cell(w, y, x).map(ComplexChar::from)
+}
+
+#[cfg(feature = "wide")]
+pub fn _mvin_wch(w: WINDOW, y: i32, x: i32) -> Option<ComplexChar> {
+    let (chars, attrs, color_pair) = mvin_cchar(w, y, x)?;
+    let mut attributes = Attributes::from(attrs as chtype & !A_COLOR);
+    attributes.set_color_pair(ColorPair(color_pair as u8));
+    let (base, combining) = chars.split_first().unwrap_or((&' ', &[]));
+    ComplexChar::with_combining(*base, combining, attributes)
+}
+
+/// Reads a cell with mvwin_wch() and splits it into its characters, attributes and color pair.
+/// The pair is the extended one where getcchar() reports it, so pairs past 255 or what a short
+/// holds set with init_extended_pair() come back whole.
+#[cfg(feature = "wide")]
+fn mvin_cchar(w: WINDOW, y: i32, x: i32) -> Option<(Vec<char>, attr_t, i16)> {
+    let mut wcval = cchar_t::default();
+    if unsafe { mvwin_wch(w, y, x, &mut wcval) } == crate::ERR {
+        return None;
+    }
+    let mut wch: [wchar_t; 6] = [0; 6];
+    let mut attrs: attr_t = 0;
+    let mut color_pair: c_short = 0;
+    // Versions of ncurses without extended pairs leave this alone
+    let mut extended_pair: c_int = -1;
+    unsafe {
+        getcchar(
+            &wcval,
+            wch.as_mut_ptr(),
+            &mut attrs,
+            &mut color_pair,
+            &mut extended_pair as *mut c_int as *mut c_void,
+        )
+    };
+    let chars = wch
+        .iter()
+        .take_while(|&&c| c != 0)
+        .map(|&c| std::char::from_u32(c as u32).unwrap_or('\u{fffd}'))
+        .collect();
+    let color_pair = match i16::try_from(extended_pair) {
+        Ok(pair) if pair >= 0 => pair,
+        _ => color_pair,
+    };
+    Some((chars, attrs, color_pair))
+}
+
+/// Packs a ComplexChar into a cchar_t, or returns None if curses rejects it.
+#[cfg(feature = "wide")]
+fn to_cchar(ch: &ComplexChar) -> Option<cchar_t> {
+    let mut wch: Vec<wchar_t> = ch.chars().iter().map(|&c| c as wchar_t).collect();
+    wch.push(0);
+    let attributes = ch.attributes();
+    let mut wcval = cchar_t::default();
+    let rc = unsafe {
+        setcchar(
+            &mut wcval,
+            wch.as_ptr(),
+            (chtype::from(attributes) & !A_COLOR) as attr_t,
+            c_short::from(attributes.color_pair().0),
+            ptr::null(),
+        )
+    };
+    if rc == crate::ERR {
+        None
+    } else {
+        Some(wcval)
+    }
+}
+
+pub fn _alloc_pair(foreground: i32, background: i32) -> i32 {
+    call_pair_function(b"alloc_pair\0", foreground, background)
+}
+
+pub fn _extended_pair_content(pair: i32) -> Option<(i32, i32)> {
+    type ExtendedPairContent = unsafe extern "C" fn(c_int, *mut c_int, *mut c_int) -> c_int;
+    let address = optional_symbol(b"extended_pair_content\0")?;
+    let extended_pair_content: ExtendedPairContent = unsafe { mem::transmute(address) };
+    let (mut foreground, mut background) = (0, 0);
+    match unsafe { extended_pair_content(pair, &mut foreground, &mut background) } {
+        crate::ERR => None,
+        _ => Some((foreground, background)),
+    }
+}
+
+pub fn _find_pair(foreground: i32, background: i32) -> i32 {
+    call_pair_function(b"find_pair\0", foreground, background)
+}
+
+fn call_pair_function(name: &[u8], foreground: i32, background: i32) -> i32 {
+    type PairFunction = unsafe extern "C" fn(c_int, c_int) -> c_int;
+    match optional_symbol(name) {
+        Some(address) => unsafe {
+            let pair_function: PairFunction = mem::transmute(address);
+            pair_function(foreground, background)
+        },
+        None => crate::ERR,
+    }
+}
+
+pub fn _free_pair(pair: i32) -> i32 {
+    type FreePair = unsafe extern "C" fn(c_int) -> c_int;
+    match optional_symbol(b"free_pair\0") {
+        Some(address) => unsafe {
+            let free_pair: FreePair = mem::transmute(address);
+            free_pair(pair)
+        },
+        None => crate::ERR,
+    }
+}
+
+pub fn _define_key(sequence: &str, keycode: i32) -> i32 {
+    let s = CString::new(sequence).unwrap();
+    unsafe { define_key(s.as_ptr(), keycode) }
+}
+
+pub fn _has_key(input: &Input) -> bool {
+    match *input {
+        Input::Character(_) => false,
+        ref key => to_keycode(key).is_some_and(|code| unsafe { has_key(code) } != 0),
+    }
+}
+
+pub fn _has_mouse() -> bool {
+    unsafe { ncurses::ll::has_mouse() != 0 }
+}
+
+pub fn _init_extended_pair(pair: i32, foreground: i32, background: i32) -> i32 {
+    type InitExtendedPair = unsafe extern "C" fn(c_int, c_int, c_int) -> c_int;
+    match optional_symbol(b"init_extended_pair\0") {
+        Some(address) => unsafe {
+            let init_extended_pair: InitExtendedPair = mem::transmute(address);
+            init_extended_pair(pair, foreground, background)
+        },
+        None => crate::ERR,
+    }
+}
+
+pub fn _is_term_resized(lines: i32, cols: i32) -> bool {
+    unsafe { is_term_resized(lines, cols) != 0 }
+}
+
+pub fn _key_defined(sequence: &str) -> i32 {
+    let s = CString::new(sequence).unwrap();
+    unsafe { key_defined(s.as_ptr()) }
+}
+
 pub fn _keyname(code: i32) -> Option<String> {
     keyname(code)
 }
 
+pub fn _on_resize() -> (i32, i32) {
+    // ncurses resizes the screen itself before it returns KEY_RESIZE
+    (LINES(), COLS())
+}
+
+pub fn _reset_color_pairs() -> i32 {
+    type ResetColorPairs = unsafe extern "C" fn();
+    match optional_symbol(b"reset_color_pairs\0") {
+        Some(address) => unsafe {
+            let reset_color_pairs: ResetColorPairs = mem::transmute(address);
+            reset_color_pairs();
+            crate::OK
+        },
+        None => crate::ERR,
+    }
+}
+
+pub fn _resize_to_terminal() -> Option<(i32, i32)> {
+    let mut size: libc::winsize = unsafe { mem::zeroed() };
+    if unsafe { libc::ioctl(libc::STDOUT_FILENO, libc::TIOCGWINSZ, &mut size) } == -1 {
+        return None;
+    }
+    let (lines, cols) = (i32::from(size.ws_row), i32::from(size.ws_col));
+    if lines == 0 || cols == 0 || !_is_term_resized(lines, cols) {
+        return None;
+    }
+    match _resizeterm(lines, cols) {
+        crate::ERR => None,
+        _ => Some((lines, cols)),
+    }
+}
+
 pub fn _resize_term(nlines: i32, ncols: i32) -> i32 {
     unsafe { resize_term(nlines, ncols) }
 }
 
+pub fn _resizeterm(lines: i32, cols: i32) -> i32 {
+    unsafe { resizeterm(lines, cols) }
+}
+
 pub fn _set_blink(_: bool) -> i32 {
     0 // Not supported
 }
 
+pub fn _set_bracketed_paste(enabled: bool) -> i32 {
+    // Binding a code of 0 removes the definition again
+    let (start, end, mode) = if enabled {
+        (KEY_PASTE_START, KEY_PASTE_END, "\x1b[?2004h")
+    } else {
+        (0, 0, "\x1b[?2004l")
+    };
+    _define_key("\x1b[200~", start);
+    _define_key("\x1b[201~", end);
+    write_to_terminal(mode)
+}
+
+pub fn _set_escdelay(milliseconds: i32) -> i32 {
+    unsafe { set_escdelay(milliseconds) }
+}
+
+pub fn _set_mouse_motion_tracking(enabled: bool) -> i32 {
+    // ncurses asks for button events at most, so ask xterm for any motion directly
+    write_to_terminal(if enabled { "\x1b[?1003h" } else { "\x1b[?1003l" })
+}
+
 pub fn _set_title(_: &str) {
     //Not supported
 }
 
+/// Looks up a function that only newer versions of ncurses have, so that calling it doesn't stop
+/// the crate from linking against older ones. The name must end in a NUL.
+fn optional_symbol(name: &[u8]) -> Option<*mut c_void> {
+    let address = unsafe { libc::dlsym(libc::RTLD_DEFAULT, name.as_ptr() as *const c_char) };
+    if address.is_null() {
+        None
+    } else {
+        Some(address)
+    }
+}
+
+/// Writes a control sequence straight to the terminal, bypassing curses' output buffer.
+fn write_to_terminal(sequence: &str) -> i32 {
+    let mut stdout = io::stdout();
+    match stdout.write_all(sequence.as_bytes()).and_then(|_| stdout.flush()) {
+        Ok(()) => crate::OK,
+        Err(_) => crate::ERR,
+    }
+}
+
 /// Converts an integer returned by getch() to a Input value
 pub fn to_special_keycode(i: i32) -> Option<Input> {
+    if i > KEY_F15 && i <= KEY_F63 {
+        return Some(Input::KeyF((i - KEY_F15 + 15) as u8));
+    }
     let index = if i <= KEY_F15 {
         i - KEY_OFFSET
     } else {
@@ -83,7 +610,83 @@ pub fn to_special_keycode(i: i32) -> Option<Input> {
     if index < 0 || index as usize >= SPECIAL_KEY_CODES.len() {
         None
     } else {
-        Some(SPECIAL_KEY_CODES[index as usize])
+        Some(SPECIAL_KEY_CODES[index as usize].clone())
+    }
+}
+
+/// Converts a single integer returned by getch() to an Input without reading any further input.
+/// Bytes past ASCII are only part of a UTF-8 sequence, so they come back as Unknown.
+pub fn from_keycode(i: i32) -> Input {
+    match to_special_keycode(i) {
+        Some(input) => input,
+        None if (0..=0x7f).contains(&i) => Input::Character(i as u8 as char),
+        None => Input::Unknown(i),
+    }
+}
+
+/// Converts an Input back to the integer getch() returns for it, the inverse of from_keycode().
+/// Returns None for characters that getch() returns as more than one byte, and for keys ncurses
+/// doesn't have.
+pub fn to_keycode(input: &Input) -> Option<i32> {
+    match input {
+        Input::Character(c) if c.is_ascii() => Some(*c as i32),
+        Input::Character(_) => None,
+        Input::Unknown(i) => Some(*i),
+        Input::KeyF(n) if *n <= 63 => Some(KEY_F15 - 15 + i32::from(*n)),
+        Input::KeyF(_) => None,
+        Input::Resize { .. } => Some(KEY_RESIZE),
+        special => SPECIAL_KEY_CODES
+            .iter()
+            .position(|skc| skc == special)
+            .map(|index| {
+                let result = index as c_int + KEY_OFFSET;
+                if result <= KEY_F15 {
+                    result
+                } else {
+                    result + 48
+                }
+            }),
+    }
+}
+
+pub fn _tigetflag(capability: &str) -> Option<bool> {
+    let capability = CString::new(capability).ok()?;
+    match unsafe { tigetflag(capability.as_ptr()) } {
+        -1 => None, // Not a boolean capability
+        flag => Some(flag != 0),
+    }
+}
+
+pub fn _tigetnum(capability: &str) -> Option<i32> {
+    let capability = CString::new(capability).ok()?;
+    match unsafe { tigetnum(capability.as_ptr()) } {
+        // -1 for an absent capability and -2 for one that isn't numeric
+        number if number < 0 => None,
+        number => Some(number),
+    }
+}
+
+pub fn _tigetstr(capability: &str) -> Option<Vec<u8>> {
+    let capability = CString::new(capability).ok()?;
+    let string = unsafe { tigetstr(capability.as_ptr()) };
+    // Null for an absent capability and (char *)-1 for one that isn't a string
+    if string.is_null() || string as isize == -1 {
+        None
+    } else {
+        Some(unsafe { CStr::from_ptr(string) }.to_bytes().to_vec())
+    }
+}
+
+pub fn _tparm1(capability: &[u8], parameter: i32) -> Option<Vec<u8>> {
+    // tiparm() is variadic, and missing before ncurses 5.9
+    type Tiparm = unsafe extern "C" fn(*const c_char, ...) -> *mut c_char;
+    let capability = CString::new(capability).ok()?;
+    let tiparm: Tiparm = unsafe { mem::transmute(optional_symbol(b"tiparm\0")?) };
+    let string = unsafe { tiparm(capability.as_ptr(), parameter as c_int) };
+    if string.is_null() {
+        None
+    } else {
+        Some(unsafe { CStr::from_ptr(string) }.to_bytes().to_vec())
     }
 }
 
@@ -99,20 +702,48 @@ pub fn _ungetch(input: &Input) -> i32 {
                 .map(|x| unsafe { ungetch(*x as c_int) })
                 .fold(0, |res, x| res.min(x))
         }
-        Input::Unknown(i) => unsafe { ungetch(i) },
-        specialKeyCode => {
-            for (i, skc) in SPECIAL_KEY_CODES.iter().enumerate() {
-                if *skc == specialKeyCode {
-                    let result = i as c_int + KEY_OFFSET;
-                    if result <= KEY_F15 {
-                        return unsafe { ungetch(result) };
-                    } else {
-                        return unsafe { ungetch(result + 48) };
-                    }
-                }
+        Input::Paste(ref text) => {
+            // Pushed back in reverse, so the markers and text are read again in order
+            let mut result = unsafe { ungetch(KEY_PASTE_END) };
+            for c in text.chars().rev() {
+                result = result.min(_ungetch(&Input::Character(c)));
             }
-            panic!("Failed to convert Input back to a c_int");
+            result.min(unsafe { ungetch(KEY_PASTE_START) })
+        }
+        _ => match to_keycode(input) {
+            Some(code) => unsafe { ungetch(code) },
+            None => crate::ERR,
+        },
+    }
+}
+
+#[cfg(not(feature = "wide"))]
+pub fn _unget_wch(c: char) -> i32 {
+    _ungetch(&Input::Character(c))
+}
+
+#[cfg(feature = "wide")]
+pub fn _unget_wch(c: char) -> i32 {
+    unsafe { unget_wch(c as winttype) }
+}
+
+#[cfg(not(feature = "wide"))]
+pub fn _wget_wch(w: WINDOW) -> Option<Input> {
+    _wgetch(w)
+}
+
+#[cfg(feature = "wide")]
+pub fn _wget_wch(w: WINDOW) -> Option<Input> {
+    let mut wch: winttype = 0;
+    match unsafe { wget_wch(w, &mut wch) } {
+        KEY_CODE_YES if wch as c_int == KEY_PASTE_START => Some(read_paste(w)),
+        KEY_CODE_YES => {
+            Some(to_special_keycode(wch as i32).unwrap_or(Input::Unknown(wch as i32)))
         }
+        crate::OK => Some(
+            std::char::from_u32(wch).map_or(Input::Unknown(wch as i32), Input::Character),
+        ),
+        _ => None,
     }
 }
 
@@ -121,28 +752,117 @@ pub fn _wgetch(w: WINDOW) -> Option<Input> {
     if i < 0 {
         None
     } else {
-        Some(to_special_keycode(i).unwrap_or_else(|| {
-            // Assume that on Linux input is UTF-8
-            fn try_decode(mut v: Vec<u8>, w: WINDOW) -> Result<String, FromUtf8Error> {
-                let res = String::from_utf8(v.clone());
-                if res.is_err() && v.len() < 4 {
-                    let next_byte = unsafe { wgetch(w) };
-                    v.push(next_byte as u8);
-                    try_decode(v, w)
-                } else {
-                    res
-                }
+        Some(decode_input(w, i))
+    }
+}
+
+pub fn _wgetch_result(w: WINDOW) -> Result<Option<Input>, i32> {
+    // wgetch() returns ERR both when the delay runs out and when reading fails, but only the
+    // latter leaves errno set
+    let errno_cleared = clear_errno();
+    let i = unsafe { wgetch(w) };
+    if i >= 0 {
+        Ok(Some(decode_input(w, i)))
+    } else if !errno_cleared {
+        // A stale errno can't be told from a failed read, so take it as the delay running out
+        Ok(None)
+    } else {
+        match io::Error::last_os_error().raw_os_error() {
+            Some(0) | None => Ok(None),
+            Some(errno) => Err(errno),
+        }
+    }
+}
+
+/// Converts a value returned by wgetch() to an Input, reading the rest of a UTF-8 sequence or
+/// bracketed paste from the window if needed.
+fn decode_input(w: WINDOW, i: c_int) -> Input {
+    if i == KEY_PASTE_START {
+        return read_paste(w);
+    }
+    to_special_keycode(i).unwrap_or_else(|| {
+        if i > 0xff {
+            // A key code with no Input variant, such as one bound with define_key()
+            return Input::Unknown(i);
+        }
+        // Assume that on Linux input is UTF-8
+        fn try_decode(mut v: Vec<u8>, w: WINDOW) -> Result<String, FromUtf8Error> {
+            let res = String::from_utf8(v.clone());
+            if res.is_err() && v.len() < 4 {
+                let next_byte = unsafe { wgetch(w) };
+                v.push(next_byte as u8);
+                try_decode(v, w)
+            } else {
+                res
             }
+        }
+
+        let v = vec![i as u8];
+        try_decode(v, w)
+            .map(|s| Input::Character(s.chars().next().unwrap()))
+            .unwrap_or_else(|error| {
+                warn!("Decoding input as UTF-8 failed: {:?}", error);
+                Input::Unknown(i)
+            })
+    })
+}
+
+/// Sets errno to 0, returning false on platforms where that isn't supported.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn clear_errno() -> bool {
+    unsafe { *libc::__errno_location() = 0 };
+    true
+}
+
+#[cfg(any(target_os = "macos", target_os = "ios", target_os = "freebsd"))]
+fn clear_errno() -> bool {
+    unsafe { *libc::__error() = 0 };
+    true
+}
 
-            let v = vec![i as u8];
-            try_decode(v, w)
-                .map(|s| Input::Character(s.chars().next().unwrap()))
-                .unwrap_or_else(|error| {
-                    warn!("Decoding input as UTF-8 failed: {:?}", error);
-                    Input::Unknown(i)
-                })
-        }))
+#[cfg(any(target_os = "openbsd", target_os = "netbsd"))]
+fn clear_errno() -> bool {
+    unsafe { *libc::__errno() = 0 };
+    true
+}
+
+#[cfg(not(any(
+    target_os = "linux",
+    target_os = "android",
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "freebsd",
+    target_os = "openbsd",
+    target_os = "netbsd"
+)))]
+fn clear_errno() -> bool {
+    false
+}
+
+/// Collects the characters that follow a bracketed paste start marker, up to the end marker.
+/// KEY_ENTER is taken as a newline, and any other key read in between, such as a resize, is
+/// pushed back to be read after the paste.
+fn read_paste(w: WINDOW) -> Input {
+    // The rest of the paste normally arrives at once, but don't wait on a terminal that never
+    // sends the end marker for longer than a second
+    let delay = _getdelay(w);
+    unsafe { wtimeout(w, 1000) };
+    let mut text = String::new();
+    let mut other_keys = Vec::new();
+    loop {
+        match _wget_wch(w) {
+            Some(Input::Character(c)) => text.push(c),
+            Some(Input::KeyEnter) => text.push('\n'),
+            Some(Input::Unknown(KEY_PASTE_END)) | None => break,
+            Some(key) => other_keys.push(key),
+        }
+    }
+    unsafe { wtimeout(w, delay) };
+    // Pushed back in reverse, so they're read again in the order they arrived
+    for key in other_keys.iter().rev() {
+        _ungetch(key);
     }
+    Input::Paste(text)
 }
 
 #[cfg(test)]
@@ -150,6 +870,7 @@ mod tests {
     use super::*;
     use crate::input::Input;
     use ncurses::{endwin, initscr};
+    use std::iter;
 
     #[test]
     fn test_key_dl_to_special_keycode() {
@@ -169,6 +890,29 @@ mod tests {
         assert_eq!(Input::KeyUp, to_special_keycode(keyUp).unwrap());
     }
 
+    #[test]
+    fn test_keycode_round_trip() {
+        let inputs = SPECIAL_KEY_CODES
+            .iter()
+            .cloned()
+            .chain((16..=63).map(Input::KeyF))
+            .chain((0..=0x7f).map(|b: u8| Input::Character(b as char)))
+            .chain(iter::once(Input::Unknown(1000)));
+        for input in inputs {
+            let code = to_keycode(&input).unwrap();
+            assert_eq!(input, from_keycode(code));
+        }
+
+        // Every code maps to an Input that converts back to the same code
+        for code in -1..0x1000 {
+            assert_eq!(Some(code), to_keycode(&from_keycode(code)));
+        }
+
+        assert_eq!(None, to_keycode(&Input::Character('é')));
+        assert_eq!(None, to_keycode(&Input::Paste("text".to_string())));
+        assert_eq!(Input::Unknown(0xc3), from_keycode(0xc3));
+    }
+
     #[test]
     fn test_ungetch() {
         let w = initscr();
@@ -189,6 +933,57 @@ mod tests {
             assert_eq!(_wgetch(w).unwrap(), *i);
         });
 
+        [Input::KeyF13, Input::KeyF(16), Input::KeyF(24), Input::KeyF(63)]
+            .iter()
+            .for_each(|i| {
+                _ungetch(i);
+                assert_eq!(_wgetch(w).unwrap(), *i);
+            });
+        _ungetch(&Input::KeyF(5));
+        assert_eq!(_wgetch(w).unwrap(), Input::KeyF5);
+        assert_eq!(crate::ERR, _ungetch(&Input::KeyF(64)));
+
+        // Codes bound with define_key() that have no variant of their own
+        _ungetch(&Input::Unknown(1000));
+        assert_eq!(_wgetch(w).unwrap(), Input::Unknown(1000));
+
+        // A paste is pushed back between the bracketed paste markers and read as a whole
+        let paste = Input::Paste("ls -l\nrm -rf build\n".to_string());
+        assert_eq!(crate::OK, _ungetch(&paste));
+        assert_eq!(_wgetch(w).unwrap(), paste);
+
+        // Enter inside a paste is part of the text, and other keys are read after the paste
+        unsafe { ungetch(KEY_PASTE_END) };
+        _ungetch(&Input::KeyDown);
+        _ungetch(&Input::KeyResize);
+        _ungetch(&Input::KeyEnter);
+        _ungetch(&Input::Character('x'));
+        unsafe { ungetch(KEY_PASTE_START) };
+        assert_eq!(_wgetch(w).unwrap(), Input::Paste("x\n".to_string()));
+        assert_eq!(_wgetch(w).unwrap(), Input::KeyResize);
+        assert_eq!(_wgetch(w).unwrap(), Input::KeyDown);
+
+        // These are PDCurses only
+        [Input::KeyAbort, Input::KeyLHelp]
+            .iter()
+            .for_each(|i| assert_eq!(crate::ERR, _ungetch(i)));
+
+        // The wide library converts characters through the locale, so non-ASCII ones only
+        // survive the trip in a UTF-8 locale
+        let utf8 = CString::new("C.UTF-8").unwrap();
+        let wide_chars: &[char] = if unsafe { setlocale(LC_ALL, utf8.as_ptr()) }.is_null() {
+            &['x']
+        } else {
+            &['é', '日', 'x']
+        };
+        wide_chars.iter().for_each(|c| {
+            assert_eq!(crate::OK, _unget_wch(*c));
+            assert_eq!(_wget_wch(w).unwrap(), Input::Character(*c));
+        });
+
+        _ungetch(&Input::KeyDown);
+        assert_eq!(_wget_wch(w).unwrap(), Input::KeyDown);
+
         endwin();
     }
 
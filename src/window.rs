@@ -1,5 +1,17 @@
-use crate::{chtype, curses, platform_specific, ptr, Input, ToChtype, ERR};
+use crate::{chtype, curses, platform_specific, ptr, Attributes, Input, ToChtype, A_CHARTEXT, A_COLOR};
+use crate::{ACS_HLINE, ACS_LLCORNER, ACS_LRCORNER, ACS_ULCORNER, ACS_URCORNER, ACS_VLINE, ERR, OK};
+use crate::PAIR_NUMBER;
+use crate::border::BorderChars;
+use crate::complexchar::ComplexChar;
+use crate::error::DrawError;
+use crate::keyevent::KeyEvent;
+use crate::mouse::MouseEvent;
+use crate::{auto_resize, fopen};
+use crate::screenbuffer::{Cell, ScreenBuffer};
 use std::ffi::CString;
+use std::io;
+use std::iter;
+use std::path::Path;
 
 #[derive(Debug)]
 pub struct Window {
@@ -16,6 +28,16 @@ type WindowPointer = *mut curses::WINDOW;
 type WindowPointer = curses::WINDOW;
 
 impl Window {
+    /// Adds the ComplexChar to the window at the current cursor position, and advances the
+    /// cursor.
+    ///
+    /// Unlike addch() this can draw any Unicode character along with its combining characters.
+    /// ncurses needs the wide feature for this and returns ERR without it. PDCurses draws the
+    /// base character only, as it has no room for combining characters.
+    pub fn add_wch(&self, ch: &ComplexChar) -> i32 {
+        platform_specific::_add_wch(self._window, ch)
+    }
+
     /// Adds the chtype ch to the window at the current cursor position, and advances the cursor.
     ///
     /// Note that chtypes can convey both text (a single character) and attributes, including a
@@ -24,20 +46,154 @@ impl Window {
         unsafe { curses::waddch(self._window, ch.to_chtype()) }
     }
 
+    /// Copies the chtypes, with their attributes, to the window starting at the current cursor
+    /// position in a single call. The cursor is not advanced and the line doesn't wrap; any
+    /// chtypes that don't fit are discarded.
+    pub fn addchstr(&self, chstr: &[chtype]) -> i32 {
+        self.addchnstr(chstr, -1)
+    }
+
+    /// Like addchstr(), but copies at most n chtypes. If n is negative, the whole slice is
+    /// copied.
+    pub fn addchnstr(&self, chstr: &[chtype], n: i32) -> i32 {
+        unsafe { curses::waddchnstr(self._window, chstr.as_ptr(), clip_to_slice(chstr, n)) }
+    }
+
+    /// Write all the characters of the string to the given window.
+    ///
+    /// The functionality is similar to calling window.addch() once for each character in the
+    /// string. On wide builds this goes through addwstr().
+    ///
+    /// Without the wide feature ncurses gives every byte of a UTF-8 string its own cell, so a
+    /// multi-byte character that would straddle the end of a line is moved to the next line
+    /// instead, and if there's no line left the string is cut short and ERR returned. A partial
+    /// sequence is never written.
+    ///
+    /// ```rust
+    /// use pancurses::{initscr, endwin, newwin};
+    /// let window = initscr();
+    /// let narrow = newwin(2, 5, 0, 0);
+    /// narrow.addstr("ααααα");
+    /// assert!(!narrow.mvinstr(0, 0).contains('\u{fffd}'));
+    /// assert!(!narrow.mvinstr(1, 0).contains('\u{fffd}'));
+    /// endwin();
+    /// ```
+    #[cfg(not(feature = "wide"))]
+    pub fn addstr<T: AsRef<str>>(&self, string: T) -> i32 {
+        platform_specific::_addstr(self._window, string.as_ref())
+    }
+
     /// Write all the characters of the string to the given window.
     ///
     /// The functionality is similar to calling window.addch() once for each character in the
-    /// string.
-	pub fn addstr<T: AsRef<str>>(&self, string: T) -> i32 {
-		let s = CString::new(string.as_ref()).unwrap();
-        unsafe { curses::waddstr(self._window, s.as_ptr()) }
+    /// string. On wide builds this goes through addwstr().
+    #[cfg(feature = "wide")]
+    pub fn addstr<T: AsRef<str>>(&self, string: T) -> i32 {
+        self.addwstr(string)
+    }
+
+    /// Write all the characters of the string to the given window as wide characters.
+    ///
+    /// The string is converted to wchar_t rather than passed as UTF-8 bytes, so combining
+    /// characters and double width characters are handled by curses regardless of the locale's
+    /// multibyte encoding. Only available with the wide feature, where addstr() uses it too.
+    ///
+    /// ```rust
+    /// use pancurses::{initscr, endwin};
+    /// let window = initscr();
+    /// window.addwstr("héllo 世界");
+    /// assert_eq!("héllo 世界", window.mvinstr(0, 0));
+    /// endwin();
+    /// ```
+    #[cfg(feature = "wide")]
+    pub fn addwstr<T: AsRef<str>>(&self, string: T) -> i32 {
+        platform_specific::_addwstr(self._window, string.as_ref())
     }
 
-    /// Write at most length characters; if length is negative, then the entire string will be
-    /// added.
+    /// Write at most length characters of the string to the given window.
+    ///
+    /// The string is clipped on a character boundary before being handed to curses, so a
+    /// multi-byte character is never partially written.
+    ///
+    /// ```rust
+    /// use pancurses::{initscr, endwin};
+    /// let window = initscr();
+    /// window.addnstr("hello", 2);
+    /// assert_eq!(2, window.get_cur_x());
+    /// endwin();
+    /// ```
     pub fn addnstr<T: AsRef<str>>(&self, string: T, length: usize) -> i32 {
-        let s = CString::new(string.as_ref()).unwrap();
-        unsafe { curses::waddnstr(self._window, s.as_ptr(), length as i32) }
+        self.addstr(clip_to_chars(string.as_ref(), length as i32))
+    }
+
+    /// Retrieve the active attributes and color pair for the given window.
+    ///
+    /// Unlike attrget() the attributes are returned as Attributes, with the color pair reported
+    /// separately rather than folded into them.
+    ///
+    /// ```rust
+    /// use pancurses::{initscr, endwin, Attribute, Attributes};
+    /// let window = initscr();
+    /// window.attr_set(Attribute::Bold, 1);
+    /// let (active_attributes, color_pair) = window.attr_get();
+    /// assert_eq!(Attributes::new() | Attribute::Bold, active_attributes);
+    /// assert_eq!(1, color_pair);
+    /// endwin();
+    /// ```
+    pub fn attr_get(&self) -> (Attributes, i16) {
+        let (attributes, color_pair) = self.attrget();
+        (Attributes::from(attributes & !A_COLOR), color_pair)
+    }
+
+    /// Turns off the given attributes without affecting any other attributes.
+    ///
+    /// Unlike attroff() this goes through the wide attribute entry point wattr_off() on ncurses.
+    /// On PDCurses it's the same as attroff().
+    pub fn attr_off<T: Into<Attributes>>(&self, attributes: T) -> i32 {
+        platform_specific::_attr_off(self._window, chtype::from(attributes.into()))
+    }
+
+    /// Turns on the given attributes without affecting any other attributes.
+    ///
+    /// Unlike attron() this goes through the wide attribute entry point wattr_on() on ncurses.
+    /// On PDCurses it's the same as attron().
+    ///
+    /// ```rust
+    /// use pancurses::{initscr, endwin, Attribute, A_BOLD};
+    /// let window = initscr();
+    /// window.attr_on(Attribute::Bold);
+    /// let (active_attributes, _) = window.attrget();
+    /// assert_eq!(A_BOLD, active_attributes);
+    /// window.attr_off(Attribute::Bold);
+    /// assert_eq!(0, window.attrget().0);
+    /// endwin();
+    /// ```
+    pub fn attr_on<T: Into<Attributes>>(&self, attributes: T) -> i32 {
+        platform_specific::_attr_on(self._window, chtype::from(attributes.into()))
+    }
+
+    /// Sets the current attributes and color pair of the given window. The color pair of the
+    /// attributes is replaced by the given one.
+    ///
+    /// Unlike attrset() this goes through the wide attribute entry point wattr_set() on ncurses.
+    /// On PDCurses the color pair is combined with the attributes and passed to attrset().
+    pub fn attr_set<T: Into<Attributes>>(&self, attributes: T, color_pair: i16) -> i32 {
+        platform_specific::_attr_set(
+            self._window,
+            chtype::from(attributes.into()) & !A_COLOR,
+            color_pair,
+        )
+    }
+
+    /// Like attr_set(), but takes the color pair as an int so that pairs set up with
+    /// init_extended_pair() past what a short holds can be selected. Returns ERR for such pairs
+    /// where init_extended_pair() isn't available.
+    pub fn attr_set_extended<T: Into<Attributes>>(&self, attributes: T, color_pair: i32) -> i32 {
+        platform_specific::_attr_set_extended(
+            self._window,
+            chtype::from(attributes.into()) & !A_COLOR,
+            color_pair,
+        )
     }
 
     /// Retrieve attributes for the given window.
@@ -89,6 +245,25 @@ impl Window {
     /// inserted to the window by addch() or insch(). Only the attribute part is used to set
     /// the background of non-blank characters, while both character and attributes are used
     /// for blank positions.
+    ///
+    /// Unlike bkgd(), cells already in the window are left as they are.
+    ///
+    /// ```rust
+    /// use pancurses::{initscr, endwin, newwin, A_CHARTEXT, A_REVERSE};
+    /// let window = initscr();
+    /// let status_bar = newwin(1, 20, 0, 0);
+    /// // ncursesw stores a null background character as a space, so start from a space
+    /// status_bar.bkgdset(' ' as u32);
+    /// status_bar.mvaddstr(0, 0, "ok");
+    /// let previous = status_bar.getbkgd();
+    /// status_bar.bkgdset('.' as u32 | A_REVERSE);
+    /// assert_eq!(0, status_bar.mvinch(0, 0) & A_REVERSE);
+    /// status_bar.erase();
+    /// assert_eq!('.' as u32, status_bar.mvinch(0, 0) & A_CHARTEXT);
+    /// status_bar.bkgdset(previous);
+    /// assert_eq!(previous, status_bar.getbkgd());
+    /// endwin();
+    /// ```
     pub fn bkgdset<T: Into<chtype>>(&self, ch: T) {
         unsafe { curses::wbkgdset(self._window, ch.into()) }
     }
@@ -121,11 +296,50 @@ impl Window {
         }
     }
 
+    /// Like border(), but applies the given attributes to every edge and corner character.
+    ///
+    /// Any character passed as zero is replaced by the default line-drawing character before the
+    /// attributes are applied, so the default border is styled as well.
+    #[allow(clippy::too_many_arguments)]
+    pub fn border_with_attributes<T: ToChtype, A: Into<chtype>>(
+        &self,
+        left_side: T,
+        right_side: T,
+        top_side: T,
+        bottom_side: T,
+        top_left_corner: T,
+        top_right_corner: T,
+        bottom_left_corner: T,
+        bottom_right_corner: T,
+        attributes: A,
+    ) -> i32 {
+        let attributes = attributes.into();
+        let style = |ch: T, default: chtype| {
+            let ch = ch.to_chtype();
+            let ch = if ch & A_CHARTEXT == 0 { default } else { ch };
+            ch | attributes
+        };
+        self.border(
+            style(left_side, ACS_VLINE()),
+            style(right_side, ACS_VLINE()),
+            style(top_side, ACS_HLINE()),
+            style(bottom_side, ACS_HLINE()),
+            style(top_left_corner, ACS_ULCORNER()),
+            style(top_right_corner, ACS_URCORNER()),
+            style(bottom_left_corner, ACS_LLCORNER()),
+            style(bottom_right_corner, ACS_LRCORNER()),
+        )
+    }
+
     /// Changes the attributes of a given number of characters starting at the current cursor
     /// location. It does not update the cursor and does not perform wrapping. A character count
     /// of -1 or greater than the remaining window width means to change attributes all the way
     /// to the end of the current line.
-    pub fn chgat(&self, n: i32, attributes: chtype, color_pair: i16) -> i32 {
+    ///
+    /// Curses gives every cell the color_pair argument, so a color_pair other than 0 overrides
+    /// the pair set in attributes. With 0 the pair in attributes is used.
+    pub fn chgat<T: Into<chtype>>(&self, n: i32, attributes: T, color_pair: i16) -> i32 {
+        let (attributes, color_pair) = chgat_pair(attributes.into(), color_pair);
         unsafe { curses::wchgat(self._window, n, attributes, color_pair, ptr::null_mut()) }
     }
 
@@ -154,16 +368,60 @@ impl Window {
 
     /// Sets the current color of the given window to the foreground/background combination
     /// described by the color pair parameter.
+    ///
+    /// The previous pair is replaced and the other attributes are left alone, unlike attron()
+    /// with COLOR_PAIR(), which ORs the new pair into the old one.
+    ///
+    /// ```rust
+    /// use pancurses::{endwin, has_colors, init_pair, initscr, start_color, Attribute};
+    /// use pancurses::{Attributes, COLOR_BLACK, COLOR_GREEN, COLOR_RED};
+    ///
+    /// let window = initscr();
+    /// if has_colors() {
+    ///     start_color();
+    ///     init_pair(1, COLOR_RED, COLOR_BLACK);
+    ///     init_pair(2, COLOR_GREEN, COLOR_BLACK);
+    ///     window.attr_on(Attribute::Bold);
+    ///     window.color_set(1);
+    ///     window.color_set(2);
+    ///     assert_eq!(2, window.current_color());
+    ///     assert_eq!((Attributes::new() | Attribute::Bold, 2), window.attr_get());
+    /// }
+    /// endwin();
+    /// ```
     pub fn color_set(&self, color_pair: i16) -> i32 {
         unsafe { curses::wcolor_set(self._window, color_pair, ptr::null_mut()) }
     }
 
+    /// Like color_set(), but takes the color pair as an int so that pairs set up with
+    /// init_extended_pair() past what a short holds can be selected. Returns ERR for such pairs
+    /// where init_extended_pair() isn't available.
+    pub fn color_set_extended(&self, color_pair: i32) -> i32 {
+        platform_specific::_color_set_extended(self._window, color_pair)
+    }
+
     /// Copy all text from this window to the destination window. The arguments src_tc and
     /// src_tr specify the top left corner of the region to be copied. dst_tc, dst_tr, dst_br,
     /// and dst_bc specify the region within the destination window to copy to. The argument
     /// "overlay", if TRUE, indicates that the copy is done non-destructively (as in overlay());
     /// blanks in the source window are not copied to the destination window. When overlay is
     /// FALSE, blanks are copied.
+    ///
+    /// ERR is returned without copying anything if either rectangle doesn't lie entirely within
+    /// its window.
+    ///
+    /// ```rust
+    /// use pancurses::{initscr, endwin, newwin, A_CHARTEXT, ERR, OK};
+    /// let window = initscr();
+    /// let source = newwin(4, 10, 0, 0);
+    /// let destination = newwin(4, 10, 5, 0);
+    /// source.mvaddstr(1, 1, "stamp");
+    /// assert_eq!(OK, source.copywin(&destination, 1, 1, 2, 3, 2, 7, false));
+    /// assert_eq!('s' as u32, destination.mvinch(2, 3) & A_CHARTEXT);
+    /// assert_eq!('p' as u32, destination.mvinch(2, 7) & A_CHARTEXT);
+    /// assert_eq!(ERR, source.copywin(&destination, 1, 1, 2, 3, 2, 10, false));
+    /// endwin();
+    /// ```
     #[allow(clippy::too_many_arguments)]
     pub fn copywin(
         &self,
@@ -176,6 +434,21 @@ impl Window {
         dst_bc: i32,
         overlay: bool,
     ) -> i32 {
+        let (src_max_y, src_max_x) = self.get_max_yx();
+        let (dst_max_y, dst_max_x) = destination_window.get_max_yx();
+        let fits = src_tr >= 0
+            && src_tc >= 0
+            && dst_tr >= 0
+            && dst_tc >= 0
+            && dst_tr <= dst_br
+            && dst_tc <= dst_bc
+            && dst_br < dst_max_y
+            && dst_bc < dst_max_x
+            && src_tr + (dst_br - dst_tr) < src_max_y
+            && src_tc + (dst_bc - dst_tc) < src_max_x;
+        if !fits {
+            return ERR;
+        }
         unsafe {
             curses::copywin(
                 self._window,
@@ -191,6 +464,16 @@ impl Window {
         }
     }
 
+    /// Returns the color pair output is currently drawn with, as set by color_set() or attr_set().
+    pub fn current_color(&self) -> i16 {
+        self.attrget().1
+    }
+
+    /// Updates the cursor position of all the window's ancestors to match the window's cursor.
+    pub fn cursyncup(&self) {
+        unsafe { curses::wcursyncup(self._window) }
+    }
+
     /// Delete the character under the cursor. All characters to the right of the cursor
     /// on the same line are moved to the left one position and hte last character on the
     /// line is filled with a blank. The cursor position does not change.
@@ -215,7 +498,20 @@ impl Window {
     /// The same as subwin(), except that begy and begx are relative to the origin of the window
     /// rather than the screen.
     ///
-    /// There is no difference between subwindows and derived windows.
+    /// There is no difference between subwindows and derived windows. The derived window shares
+    /// its character buffer with the parent, so drawing in one is visible in the other, but the
+    /// parent should be touched with touch() before it is refreshed.
+    ///
+    /// ```rust
+    /// use pancurses::{initscr, endwin, newwin, A_CHARTEXT};
+    /// let window = initscr();
+    /// let parent = newwin(10, 20, 2, 4);
+    /// let child = parent.derwin(5, 10, 3, 6).unwrap();
+    /// assert_eq!((5, 10), child.get_beg_yx());
+    /// child.mvaddch(1, 2, 'x');
+    /// assert_eq!('x' as u32, parent.mvinch(4, 8) & A_CHARTEXT);
+    /// endwin();
+    /// ```
     pub fn derwin(&self, nlines: i32, ncols: i32, begy: i32, begx: i32) -> Result<Window, i32> {
         self.subwin(
             nlines,
@@ -225,13 +521,74 @@ impl Window {
         )
     }
 
+    /// Returns all input that's already queued, without waiting for more. The delay mode set by
+    /// nodelay() or timeout() is restored afterwards, so this suits games that handle every
+    /// pending key once per frame.
+    ///
+    /// ```rust
+    /// use pancurses::{initscr, endwin, Input};
+    /// let window = initscr();
+    /// window.timeout(250);
+    /// window.ungetch(&Input::Character('b'));
+    /// window.ungetch(&Input::KeyUp);
+    /// assert_eq!(vec![Input::KeyUp, Input::Character('b')], window.drain_input());
+    /// assert_eq!(Vec::<Input>::new(), window.drain_input());
+    /// endwin();
+    /// ```
+    pub fn drain_input(&self) -> Vec<Input> {
+        let _non_blocking = NonBlocking::new(self);
+        iter::from_fn(|| self.getch()).collect()
+    }
+
+    /// Draw a border around the edges of the window using the given characters.
+    pub fn draw_border(&self, chars: &BorderChars) -> i32 {
+        self.border(
+            chars.left,
+            chars.right,
+            chars.top,
+            chars.bottom,
+            chars.top_left,
+            chars.top_right,
+            chars.bottom_left,
+            chars.bottom_right,
+        )
+    }
+
     /// Draw a border around the edge of the window. If any argument is zero, an appropriate
     /// default is used.
     pub fn draw_box<T: ToChtype>(&self, verch: T, horch: T) -> i32 {
         platform_specific::_draw_box(self._window, verch.to_chtype(), horch.to_chtype())
     }
 
+    /// Like draw_box(), but applies the given attributes to every edge and corner character.
+    ///
+    /// ```rust
+    /// use pancurses::{initscr, endwin, newwin, init_pair, start_color, Attribute, ColorPair};
+    /// use pancurses::{Attributes, COLOR_BLACK, COLOR_RED};
+    /// let window = initscr();
+    /// start_color();
+    /// init_pair(1, COLOR_RED, COLOR_BLACK);
+    /// let frame = newwin(4, 10, 0, 0);
+    /// frame.draw_box_with_attributes(0, 0, Attribute::Bold | ColorPair(1));
+    /// let corner = Attributes::from(frame.mvinch(0, 0));
+    /// assert!(corner.is_bold());
+    /// assert_eq!(ColorPair(1), corner.color_pair());
+    /// endwin();
+    /// ```
+    pub fn draw_box_with_attributes<T: ToChtype, A: Into<chtype>>(
+        &self,
+        verch: T,
+        horch: T,
+        attributes: A,
+    ) -> i32 {
+        let (verch, horch) = (verch.to_chtype(), horch.to_chtype());
+        self.border_with_attributes(verch, verch, horch, horch, 0, 0, 0, 0, attributes)
+    }
+
     /// Creates an exact duplicate of the window.
+    ///
+    /// The duplicate owns its own copy of the contents and is deleted independently of the
+    /// original. See try_clone() for a version that reports failure.
     pub fn dupwin(&self) -> Window {
         let dup_win = unsafe { curses::dupwin(self._window) };
         Window {
@@ -241,6 +598,20 @@ impl Window {
     }
 
     /// Reports whether the given screen-relative y, x coordinates fall within the window.
+    ///
+    /// This is handy for routing a mouse event from getmouse() to the window that was clicked.
+    ///
+    /// ```rust
+    /// use pancurses::{initscr, endwin, newwin};
+    /// let window = initscr();
+    /// let panel = newwin(5, 10, 3, 7);
+    /// let button = panel.derwin(1, 4, 2, 3).unwrap();
+    /// assert!(panel.enclose(3, 7));
+    /// assert!(!panel.enclose(8, 7));
+    /// assert!(button.enclose(5, 13));
+    /// assert!(!button.enclose(5, 9));
+    /// endwin();
+    /// ```
     pub fn enclose(&self, y: i32, x: i32) -> bool {
         unsafe { curses::wenclose(self._window, y, x) > 0 }
     }
@@ -250,22 +621,126 @@ impl Window {
         unsafe { curses::werase(self._window) }
     }
 
+    /// Throws away any type-ahead that has been typed by the user and has not yet been read by
+    /// the program. The same as the free function flushinp(), as the input queue isn't per
+    /// window.
+    pub fn flushinp(&self) -> i32 {
+        unsafe { curses::flushinp() }
+    }
+
+    /// Reads a line of input, as getnstr() does, of at most 1024 characters.
+    pub fn getstr(&self) -> Result<String, i32> {
+        self.getnstr(1024)
+    }
+
+    /// Reads a line of input of at most max characters, up to a newline or carriage return
+    /// which isn't included in the result. The erase and kill characters are handled, and
+    /// input is echoed if echo() is enabled.
+    ///
+    /// On narrow builds max counts bytes and the input is decoded as UTF-8, with invalid
+    /// sequences replaced by U+FFFD. Wide builds read characters with wgetn_wstr().
+    ///
+    /// ```rust
+    /// use pancurses::{initscr, endwin, Input};
+    /// let window = initscr();
+    /// for c in "yes\n".chars().rev() {
+    ///     window.ungetch(&Input::Character(c));
+    /// }
+    /// assert_eq!(Ok("yes".to_string()), window.getnstr(10));
+    /// endwin();
+    /// ```
+    pub fn getnstr(&self, max: usize) -> Result<String, i32> {
+        platform_specific::_getnstr(self._window, max)
+    }
+
     /// Get the upper-left y coordinate of this window
     pub fn get_beg_y(&self) -> i32 {
         unsafe { curses::getbegy(self._window) }
     }
 
-    // Get the upper-left x coordinate of this window
+    /// Get the upper-left x coordinate of this window
     pub fn get_beg_x(&self) -> i32 {
         unsafe { curses::getbegx(self._window) }
     }
 
     /// Get the upper-left y and x coordinates of this window
+    ///
+    /// ```rust
+    /// use pancurses::{initscr, endwin, newwin};
+    /// let window = initscr();
+    /// let subject = newwin(5, 10, 3, 7);
+    /// assert_eq!((3, 7), subject.get_beg_yx());
+    /// assert_eq!((-1, -1), subject.get_par_yx());
+    /// let child = subject.derwin(2, 3, 1, 2).unwrap();
+    /// assert_eq!((4, 9), child.get_beg_yx());
+    /// assert_eq!((1, 2), child.get_par_yx());
+    /// endwin();
+    /// ```
     pub fn get_beg_yx(&self) -> (i32, i32) {
         (self.get_beg_y(), self.get_beg_x())
     }
 
-    /// Returns the given window's current background character and attributes.
+    /// Get the y coordinate of this subwindow relative to its parent, or -1 if it isn't a
+    /// subwindow
+    pub fn get_par_y(&self) -> i32 {
+        unsafe { curses::getpary(self._window) }
+    }
+
+    /// Get the x coordinate of this subwindow relative to its parent, or -1 if it isn't a
+    /// subwindow
+    pub fn get_par_x(&self) -> i32 {
+        unsafe { curses::getparx(self._window) }
+    }
+
+    /// Get the y and x coordinates of this subwindow relative to its parent, or (-1, -1) if it
+    /// isn't a subwindow
+    pub fn get_par_yx(&self) -> (i32, i32) {
+        (self.get_par_y(), self.get_par_x())
+    }
+
+    /// Fills the rectangle of height rows and width columns at (y, x) with ch, with the given
+    /// attributes applied. The rectangle is clipped to the window, so parts outside of it,
+    /// including those at negative coordinates, are ignored. The cursor position is not changed.
+    ///
+    /// ```rust
+    /// use pancurses::{initscr, endwin, newwin, Attribute, A_DIM, OK};
+    /// let window = initscr();
+    /// let background = newwin(4, 8, 0, 0);
+    /// assert_eq!(OK, background.fill_region(-1, 6, 3, 5, '#', Attribute::Dim));
+    /// assert_eq!("      ##", background.mvinstr(0, 0));
+    /// assert_eq!("      ##", background.mvinstr(1, 0));
+    /// assert_eq!("", background.mvinstr(2, 0));
+    /// assert_eq!(A_DIM, background.mvinch(1, 7) & A_DIM);
+    /// assert_eq!(OK, background.fill_region(1, 1, 0, 5, '#', Attribute::Dim));
+    /// endwin();
+    /// ```
+    pub fn fill_region<T: ToChtype, A: Into<Attributes>>(
+        &self,
+        y: i32,
+        x: i32,
+        height: i32,
+        width: i32,
+        ch: T,
+        attributes: A,
+    ) -> i32 {
+        let (max_y, max_x) = self.get_max_yx();
+        let (top, left) = (y.max(0), x.max(0));
+        let bottom = y.saturating_add(height).min(max_y);
+        let right = x.saturating_add(width).min(max_x);
+        if top >= bottom || left >= right {
+            return OK;
+        }
+        let ch = ch.to_chtype() | chtype::from(attributes.into());
+        let (cursor_y, cursor_x) = self.get_cur_yx();
+        let result = (top..bottom)
+            .map(|row| self.mvhline(row, left, ch, right - left))
+            .fold(OK, |result, row_result| result.min(row_result));
+        self.mv(cursor_y, cursor_x);
+        result
+    }
+
+    /// Returns the given window's current background character and attributes, as set by bkgd()
+    /// or bkgdset().
     pub fn getbkgd(&self) -> chtype {
         unsafe { curses::getbkgd(self._window) }
     }
@@ -282,7 +757,41 @@ impl Window {
     /// If nodelay(win, TRUE) has been called on the window and no input is waiting, None is
     /// returned.
     pub fn getch(&self) -> Option<Input> {
-        platform_specific::_wgetch(self._window)
+        auto_resize(platform_specific::_wgetch(self._window))
+    }
+
+    /// Read a character as getch() does, but tell an expired delay apart from a failed read.
+    ///
+    /// Returns Ok(None) if no input arrived before the delay set with timeout() or nodelay() ran
+    /// out, and Err with the OS error code if reading failed, for instance Err(libc::EINTR) when a
+    /// signal interrupted the wait. PDCurses never fails this way, so it only returns Ok, and
+    /// neither does ncurses on Unix systems other than Linux, Android, macOS, iOS and the BSDs,
+    /// where errno can't be cleared before reading.
+    ///
+    /// ```rust
+    /// use pancurses::{initscr, endwin};
+    /// let window = initscr();
+    /// window.timeout(0);
+    /// assert_eq!(Ok(None), window.getch_result());
+    /// endwin();
+    /// ```
+    pub fn getch_result(&self) -> Result<Option<Input>, i32> {
+        platform_specific::_wgetch_result(self._window).map(auto_resize)
+    }
+
+    /// Read a keyboard event as a whole character, as getch() does, but through wget_wch() on
+    /// wide builds so curses decodes multi-byte input according to the locale. Function keys
+    /// are still returned as their Input variants.
+    ///
+    /// ```rust
+    /// use pancurses::{initscr, endwin, Input};
+    /// let window = initscr();
+    /// window.unget_wch('x');
+    /// assert_eq!(Some(Input::Character('x')), window.get_wch());
+    /// endwin();
+    /// ```
+    pub fn get_wch(&self) -> Option<Input> {
+        auto_resize(platform_specific::_wget_wch(self._window))
     }
 
     /// Return the current x coordinate of the cursor
@@ -300,6 +809,63 @@ impl Window {
         (self.get_cur_y(), self.get_cur_x())
     }
 
+    /// Read a key press, as getch() does, along with the Ctrl, Alt and Shift modifiers.
+    ///
+    /// PDCurses reports the modifiers directly. On ncurses they're recognized from the shifted
+    /// key codes such as KEY_SLEFT, the extended terminfo keys such as kLFT5 (Ctrl+Left) that
+    /// ncurses gives codes when keypad() is enabled, and an ESC read immediately before another
+    /// key, which is how terminals send Alt. Combinations that aren't recognized come through as
+    /// the plain key with no modifiers. With `set_auto_resize()` enabled a resize comes through
+    /// as `Input::Resize`, as it does from getch().
+    ///
+    /// ```rust
+    /// use pancurses::{initscr, endwin, set_auto_resize, Input, KeyEvent};
+    /// let window = initscr();
+    /// window.keypad(true);
+    /// window.ungetch(&Input::KeySLeft);
+    /// let event = window.get_key_event().unwrap();
+    /// assert_eq!(Input::KeyLeft, event.key);
+    /// assert!(event.shift);
+    /// window.ungetch(&Input::Character('x'));
+    /// window.ungetch(&Input::Character('\u{1b}'));
+    /// if cfg!(unix) {
+    ///     let alt_x = window.get_key_event().unwrap();
+    ///     assert_eq!(KeyEvent { alt: true, ..KeyEvent::new(Input::Character('x')) }, alt_x);
+    /// }
+    /// set_auto_resize(true);
+    /// window.ungetch(&Input::KeyResize);
+    /// let (rows, cols) = window.get_max_yx();
+    /// assert_eq!(Input::Resize { rows, cols }, window.get_key_event().unwrap().key);
+    /// set_auto_resize(false);
+    /// endwin();
+    /// ```
+    pub fn get_key_event(&self) -> Option<KeyEvent> {
+        let event = platform_specific::_get_key_event(self._window)?;
+        let key = auto_resize(Some(event.key))?;
+        Some(KeyEvent { key, ..event })
+    }
+
+    /// Reads the mouse event that made getch() return KeyMouse, decoded into a MouseEvent. The
+    /// coordinates are relative to the screen, as with getmouse().
+    ///
+    /// ```no_run
+    /// use pancurses::{initscr, endwin, mousemask, Input, MouseEventKind, DEFAULT_MOUSE_EVENTS};
+    /// let window = initscr();
+    /// window.keypad(true);
+    /// mousemask(DEFAULT_MOUSE_EVENTS, None);
+    /// if let Some(Input::KeyMouse) = window.getch() {
+    ///     if let Ok(event) = window.get_mouse_event() {
+    ///         if event.kind == MouseEventKind::Click && event.button == Some(1) {
+    ///             window.mvaddstr(event.y, event.x, "x");
+    ///         }
+    ///     }
+    /// }
+    /// endwin();
+    /// ```
+    pub fn get_mouse_event(&self) -> Result<MouseEvent, i32> {
+        platform_specific::_getmouse().map(MouseEvent::from)
+    }
+
     /// Return the maximum x value of this Window, in other words the number of columns.
     pub fn get_max_x(&self) -> i32 {
         unsafe { curses::getmaxx(self._window) }
@@ -317,6 +883,8 @@ impl Window {
 
     /// Draw a horizontal line using ch from the current cursor position. The line is at most
     /// n characters long, or as many as fit into the window.
+    ///
+    /// Attributes can be OR'd into ch to draw a styled line, e.g. `ACS_HLINE() | A_BOLD`.
     pub fn hline<T: ToChtype>(&self, ch: T, n: i32) -> i32 {
         unsafe { curses::whline(self._window, ch.to_chtype(), n) }
     }
@@ -325,6 +893,24 @@ impl Window {
     /// The n bottom lines are lost. For negative n, delete n lines (starting with the one under
     /// the cursor), and move the remaining lines up. The bottom n lines are cleared.
     /// The current cursor position remains the same.
+    ///
+    /// The whole window below the cursor is affected, regardless of any scrolling region set
+    /// with setscrreg(), and lines pushed off the bottom are lost for good.
+    ///
+    /// ```rust
+    /// use pancurses::{initscr, endwin, newwin};
+    /// let window = initscr();
+    /// let list = newwin(6, 4, 0, 0);
+    /// for (y, row) in ["a", "b", "c", "d", "e", "f"].iter().enumerate() {
+    ///     list.mvaddstr(y as i32, 0, row);
+    /// }
+    /// list.mv(1, 0);
+    /// list.insdelln(3);
+    /// assert_eq!("a,,,,b,c", list.lines().collect::<Vec<_>>().join(","));
+    /// list.insdelln(-2);
+    /// assert_eq!("a,,b,c,,", list.lines().collect::<Vec<_>>().join(","));
+    /// endwin();
+    /// ```
     pub fn insdelln(&self, n: i32) -> i32 {
         unsafe { curses::winsdelln(self._window, n) }
     }
@@ -345,6 +931,11 @@ impl Window {
         unsafe { curses::is_wintouched(self._window) > 0 }
     }
 
+    /// Same as is_touched(), under its curses name.
+    pub fn is_wintouched(&self) -> bool {
+        self.is_touched()
+    }
+
     /// Controls whether getch() returns function/special keys as single key codes (e.g., the left
     /// arrow key as KEY_LEFT).
     ///
@@ -354,82 +945,482 @@ impl Window {
         unsafe { curses::keypad(self._window, use_keypad as u8) }
     }
 
-    /// Insert the character ch before the character under the cursor.
-    ///
-    /// All characters to the right of the cursor are moved one space to the right, with the
-    /// possibility of the rightmost character on the line being lost. The insertion operation does
-    /// not change the cursor position.
-    pub fn insch<T: ToChtype>(&self, ch: T) -> i32 {
-        unsafe { curses::winsch(self._window, ch.to_chtype()) }
+    /// If enabled, curses leaves the hardware cursor wherever the last update happens to put it
+    /// instead of moving it back to the window's cursor. This saves cursor movement in
+    /// applications that don't show a cursor.
+    pub fn leaveok(&self, bf: bool) -> i32 {
+        unsafe { curses::leaveok(self._window, bf as u8) }
     }
 
-    /// Converts between screen-relative and window-relative coordinates.
-    ///
-    /// A to_screen parameter of true means to convert from window to screen;
-    /// otherwise the reverse.
-    pub fn mouse_trafo(&self, y: i32, x: i32, to_screen: bool) -> (i32, i32) {
-        let mut mut_y = y;
-        let mut mut_x = x;
-        unsafe { curses::wmouse_trafo(self._window, &mut mut_y, &mut mut_x, to_screen as u8); }
-        (mut_y, mut_x)
+    /// If enabled, curses considers using the terminal's hardware insert/delete character
+    /// feature. This is advisory on PDCurses, where it has no effect.
+    pub fn idcok(&self, bf: bool) {
+        unsafe { curses::idcok(self._window, bf as u8) }
     }
 
-    /// The cursor associated with the window is moved to the given location.
-    ///
-    /// This does not move the physical cursor of the terminal until refresh() is called.  The
-    /// position specified is relative to the upper left corner of the window, which is (0,0).
-    pub fn mv(&self, y: i32, x: i32) -> i32 {
-        unsafe { curses::wmove(self._window, y, x) }
+    /// If enabled, curses considers using the terminal's hardware insert/delete line feature.
+    /// This is advisory on PDCurses, where it has no effect.
+    pub fn idlok(&self, bf: bool) -> i32 {
+        unsafe { curses::idlok(self._window, bf as u8) }
     }
 
-    /// moves the cursor to the specified position and adds ch to the specified window
-    pub fn mvaddch<T: ToChtype>(&self, y: i32, x: i32, ch: T) -> i32 {
-        unsafe { curses::mvwaddch(self._window, y, x, ch.to_chtype()) }
+    /// If enabled, any change to the window automatically causes a refresh(). This may degrade
+    /// performance considerably, so it's disabled by default.
+    pub fn immedok(&self, bf: bool) {
+        unsafe { curses::immedok(self._window, bf as u8) }
     }
 
-    /// Write all the characters of the string str to the given window. The functionality is
-    /// similar to calling waddch() once for each character in the string.
-    pub fn mvaddstr<T: AsRef<str>>(&self, y: i32, x: i32, string: T) -> i32 {
-        let s = CString::new(string.as_ref()).unwrap();
-        unsafe { curses::mvwaddstr(self._window, y, x, s.as_ptr()) }
+    /// Reads back the character under the cursor together with any combining characters, as
+    /// drawn by add_wch(). See mvin_wch().
+    pub fn in_wch(&self) -> Option<ComplexChar> {
+        let (y, x) = self.get_cur_yx();
+        self.mvin_wch(y, x)
     }
 
-    /// Write the first'n' characters of the string str to the given window.
-    pub fn mvaddnstr<T: AsRef<str>>(&self, y: i32, x: i32, string: T, n: i32) -> i32 {
-        let s = CString::new(string.as_ref()).unwrap();
-        unsafe { curses::mvwaddnstr(self._window, y, x, s.as_ptr(), n) }
+    /// Reads the characters and attributes from the current cursor position to the end of the
+    /// line.
+    pub fn inchstr(&self) -> Vec<chtype> {
+        self.inchnstr(self.get_max_x() - self.get_cur_x())
     }
 
-    /// Moves the cursor and changes the attributes of a given number of characters starting at the
-    /// cursor location. It does not update the cursor and does not perform wrapping. A character count
-    /// of -1 or greater than the remaining window width means to change attributes all the way
-    /// to the end of the current line.
-    pub fn mvchgat(&self, y: i32, x: i32, n: i32, attributes: chtype, color_pair: i16) -> i32 {
-        unsafe {
-            curses::mvwchgat(
-                self._window,
-                y,
-                x,
-                n,
-                attributes,
-                color_pair,
-                ptr::null_mut(),
-            )
+    /// Reads at most n cells, as chtypes, from the current cursor position, stopping at the end
+    /// of the line. The length of the returned vector is the number of cells actually read. A
+    /// negative n reads to the end of the line.
+    ///
+    /// ```rust
+    /// use pancurses::{initscr, endwin, A_CHARTEXT};
+    /// let window = initscr();
+    /// window.mvaddstr(0, 0, "xyz");
+    /// window.mv(0, 1);
+    /// let cells = window.inchnstr(-1);
+    /// assert_eq!(window.get_max_x() as usize - 1, cells.len());
+    /// assert_eq!(['y' as u32, 'z' as u32], [cells[0] & A_CHARTEXT, cells[1] & A_CHARTEXT]);
+    /// endwin();
+    /// ```
+    pub fn inchnstr(&self, n: i32) -> Vec<chtype> {
+        let n = if n < 0 {
+            (self.get_max_x() - self.get_cur_x()).max(0)
+        } else {
+            n
+        };
+        let mut buffer: Vec<chtype> = vec![0; n as usize + 1];
+        let result = unsafe { curses::winchnstr(self._window, buffer.as_mut_ptr(), n) };
+        if result == ERR {
+            return Vec::new();
         }
+        let length = buffer.iter().position(|&ch| ch == 0).unwrap_or(n as usize);
+        buffer.truncate(length);
+        buffer
     }
 
-    /// Moves a derived window (or subwindow) inside its parent window.
+    /// Reads at most n characters from the window, starting at the current cursor position and
+    /// stopping at the end of the line. Trailing blanks are stripped from the result. A negative
+    /// n reads to the end of the line.
     ///
-    /// The screen-relative parameters of the window are not changed. This routine is used to
-    /// display different parts of the parent window at the same physical position on the screen.
-    pub fn mvderwin(&self, pary: i32, parx: i32) -> i32 {
-        unsafe { curses::mvderwin(self._window, pary, parx) }
+    /// On wide builds the text is read with winnwstr() so non-ASCII characters round-trip.
+    ///
+    /// ```rust
+    /// use pancurses::{initscr, endwin};
+    /// let window = initscr();
+    /// window.mvaddstr(0, 0, "A line long enough to need more than one byte");
+    /// window.mv(0, 2);
+    /// assert_eq!("line long enough to need more than one byte", window.innstr(-1));
+    /// assert_eq!("long", window.mvinnstr(0, 7, 4));
+    /// assert_eq!("enough to need more than one byte", window.mvinnstr(0, 12, -1));
+    /// endwin();
+    /// ```
+    pub fn innstr(&self, n: i32) -> String {
+        let mut string = platform_specific::_innstr(self._window, n);
+        let trimmed_length = string.trim_end_matches(' ').len();
+        string.truncate(trimmed_length);
+        string
     }
 
-    /// Retrieves the character and attribute from the specified window position, in the form of a
-    /// chtype.
-    pub fn mvinch(&self, y: i32, x: i32) -> chtype {
-        unsafe { curses::mvwinch(self._window, y, x) }
+    /// Returns an iterator over input events, calling getch() for each one. With the default
+    /// blocking reads this waits for every event; with nodelay() or timeout() in effect the
+    /// iterator ends as soon as getch() returns None.
+    ///
+    /// ```rust
+    /// use pancurses::{initscr, endwin, Input};
+    /// let window = initscr();
+    /// window.nodelay(true);
+    /// "hi\n".chars().rev().for_each(|c| { window.ungetch(&Input::Character(c)); });
+    /// let line: String = window
+    ///     .input_events()
+    ///     .take_while(|input| *input != Input::Character('\n'))
+    ///     .filter_map(|input| match input {
+    ///         Input::Character(c) => Some(c),
+    ///         _ => None,
+    ///     })
+    ///     .collect();
+    /// assert_eq!("hi", line);
+    /// endwin();
+    /// ```
+    pub fn input_events(&self) -> impl Iterator<Item = Input> + '_ {
+        iter::from_fn(move || self.getch())
+    }
+
+    /// Insert the character ch before the character under the cursor.
+    ///
+    /// All characters to the right of the cursor are moved one space to the right, with the
+    /// possibility of the rightmost character on the line being lost. The insertion operation does
+    /// not change the cursor position.
+    pub fn insch<T: ToChtype>(&self, ch: T) -> i32 {
+        unsafe { curses::winsch(self._window, ch.to_chtype()) }
+    }
+
+    /// Insert the string before the character under the cursor.
+    ///
+    /// All characters to the right of the cursor are shifted right, with the possibility of the
+    /// rightmost characters on the line being lost. The cursor position is not changed.
+    pub fn insstr<T: AsRef<str>>(&self, string: T) -> i32 {
+        let s = CString::new(string.as_ref()).unwrap();
+        unsafe { curses::winsstr(self._window, s.as_ptr()) }
+    }
+
+    /// Reads the characters from the current cursor position to the end of the line. Trailing
+    /// blanks are stripped from the result.
+    pub fn instr(&self) -> String {
+        self.innstr(self.get_max_x() - self.get_cur_x())
+    }
+
+    /// Insert at most n characters of the string before the character under the cursor. If n
+    /// is negative, then the entire string will be inserted.
+    ///
+    /// As with addnstr() the string is clipped on a character boundary before being handed to
+    /// curses. The cursor position is not changed.
+    pub fn insnstr<T: AsRef<str>>(&self, string: T, n: i32) -> i32 {
+        let clipped = clip_to_chars(string.as_ref(), n);
+        let s = CString::new(clipped).unwrap();
+        unsafe { curses::winsnstr(self._window, s.as_ptr(), clipped.len() as i32) }
+    }
+
+    /// Controls whether the terminal passes 8 bit input through intact. Enabled, characters typed
+    /// with Alt held down arrive with the high bit set on terminals that send them that way;
+    /// disabled, the high bit is stripped.
+    ///
+    /// Many terminals send Alt+x as an ESC followed by x instead, whatever this setting. To tell
+    /// that apart from a lone Escape press, read the next input without waiting after an ESC:
+    ///
+    /// ```rust
+    /// use pancurses::{initscr, endwin, Input};
+    /// let window = initscr();
+    /// window.meta(true);
+    /// window.ungetch(&Input::Character('x'));
+    /// window.ungetch(&Input::Character('\u{1b}'));
+    /// if let Some(Input::Character('\u{1b}')) = window.getch() {
+    ///     window.nodelay(true);
+    ///     let next = window.getch();
+    ///     window.nodelay(false);
+    ///     assert_eq!(Some(Input::Character('x')), next); // Alt+x rather than Escape, then x
+    /// }
+    /// endwin();
+    /// ```
+    pub fn meta(&self, enabled: bool) -> i32 {
+        unsafe { curses::meta(self._window, enabled as u8) }
+    }
+
+    /// Converts between screen-relative and window-relative coordinates.
+    ///
+    /// A to_screen parameter of true means to convert from window to screen;
+    /// otherwise the reverse. Returns None if the point lies outside the window, so this can
+    /// route a click from get_mouse_event() and find where in the window it landed in one go.
+    ///
+    /// ```rust
+    /// use pancurses::{initscr, endwin, newwin};
+    /// let window = initscr();
+    /// let sidebar = newwin(10, 20, 0, 0);
+    /// let list = newwin(10, 30, 0, 25);
+    /// assert_eq!(None, sidebar.mouse_trafo(4, 30, false));
+    /// assert_eq!(Some((4, 5)), list.mouse_trafo(4, 30, false));
+    /// assert_eq!(Some((4, 30)), list.mouse_trafo(4, 5, true));
+    /// assert_eq!(None, list.mouse_trafo(12, 5, true));
+    /// endwin();
+    /// ```
+    pub fn mouse_trafo(&self, y: i32, x: i32, to_screen: bool) -> Option<(i32, i32)> {
+        let mut mut_y = y;
+        let mut mut_x = x;
+        let inside = unsafe {
+            curses::wmouse_trafo(self._window, &mut mut_y, &mut mut_x, to_screen as u8)
+        };
+        if inside != 0 {
+            Some((mut_y, mut_x))
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator over the text of each line of the window, with trailing blanks
+    /// stripped. The cursor position is left unchanged.
+    ///
+    /// Line-drawing characters, such as those drawn by draw_box(), are replaced by their Unicode
+    /// equivalents, e.g. ACS_HLINE() becomes '─' and ACS_ULCORNER() becomes '┌'.
+    ///
+    /// ```rust
+    /// use pancurses::{initscr, endwin, newwin};
+    /// let window = initscr();
+    /// let dialog = newwin(3, 7, 0, 0);
+    /// dialog.draw_box(0, 0);
+    /// dialog.mvaddstr(1, 1, "Hi");
+    /// let lines: Vec<String> = dialog.lines().collect();
+    /// assert_eq!(vec!["┌─────┐", "│Hi   │", "└─────┘"], lines);
+    /// assert_eq!("┌─────┐\n│Hi   │\n└─────┘", dialog.to_string_lossy());
+    /// endwin();
+    /// ```
+    pub fn lines(&self) -> impl Iterator<Item = String> + '_ {
+        (0..self.get_max_y()).map(move |y| self.line_text(y))
+    }
+
+    fn line_text(&self, y: i32) -> String {
+        let (cursor_y, cursor_x) = self.get_cur_yx();
+        let line: String = (0..self.get_max_x())
+            .filter_map(|x| self.read_cell(y, x))
+            .map(|cell| {
+                if cell.attributes.is_alternative_char_set() {
+                    acs_to_unicode(cell.ch)
+                } else {
+                    cell.ch
+                }
+            })
+            .collect();
+        self.mv(cursor_y, cursor_x);
+        line.trim_end_matches(' ').to_string()
+    }
+
+    /// The cursor associated with the window is moved to the given location.
+    ///
+    /// This does not move the physical cursor of the terminal until refresh() is called.  The
+    /// position specified is relative to the upper left corner of the window, which is (0,0).
+    pub fn mv(&self, y: i32, x: i32) -> i32 {
+        unsafe { curses::wmove(self._window, y, x) }
+    }
+
+    /// Moves the cursor to the specified position and adds the ComplexChar as add_wch() does.
+    pub fn mvadd_wch(&self, y: i32, x: i32, ch: &ComplexChar) -> i32 {
+        platform_specific::_mvadd_wch(self._window, y, x, ch)
+    }
+
+    /// moves the cursor to the specified position and adds ch to the specified window
+    pub fn mvaddch<T: ToChtype>(&self, y: i32, x: i32, ch: T) -> i32 {
+        unsafe { curses::mvwaddch(self._window, y, x, ch.to_chtype()) }
+    }
+
+    /// Moves the cursor and then copies the chtypes to the window as addchstr() does.
+    ///
+    /// ```rust
+    /// use pancurses::{initscr, endwin, A_BOLD, A_REVERSE};
+    /// let window = initscr();
+    /// let row = ['o' as u32 | A_BOLD, 'k' as u32 | A_REVERSE];
+    /// window.mvaddchstr(2, 3, &row);
+    /// assert_eq!(row.to_vec(), window.mvinchnstr(2, 3, 2));
+    /// assert_eq!((2, 3), window.get_cur_yx());
+    /// endwin();
+    /// ```
+    pub fn mvaddchstr(&self, y: i32, x: i32, chstr: &[chtype]) -> i32 {
+        self.mvaddchnstr(y, x, chstr, -1)
+    }
+
+    /// Moves the cursor and then copies at most n chtypes to the window as addchnstr() does.
+    pub fn mvaddchnstr(&self, y: i32, x: i32, chstr: &[chtype], n: i32) -> i32 {
+        unsafe {
+            curses::mvwaddchnstr(self._window, y, x, chstr.as_ptr(), clip_to_slice(chstr, n))
+        }
+    }
+
+    /// Write all the characters of the string str to the given window. The functionality is
+    /// similar to calling waddch() once for each character in the string. On wide builds this
+    /// goes through mvaddwstr().
+    #[cfg(not(feature = "wide"))]
+    pub fn mvaddstr<T: AsRef<str>>(&self, y: i32, x: i32, string: T) -> i32 {
+        if self.mv(y, x) == ERR {
+            return ERR;
+        }
+        self.addstr(string)
+    }
+
+    /// Write all the characters of the string str to the given window. The functionality is
+    /// similar to calling waddch() once for each character in the string. On wide builds this
+    /// goes through mvaddwstr().
+    #[cfg(feature = "wide")]
+    pub fn mvaddstr<T: AsRef<str>>(&self, y: i32, x: i32, string: T) -> i32 {
+        self.mvaddwstr(y, x, string)
+    }
+
+    /// Moves the cursor and then writes the string as wide characters, as addwstr() does.
+    #[cfg(feature = "wide")]
+    pub fn mvaddwstr<T: AsRef<str>>(&self, y: i32, x: i32, string: T) -> i32 {
+        if self.mv(y, x) == ERR {
+            return ERR;
+        }
+        self.addwstr(string)
+    }
+
+    /// Write the first'n' characters of the string str to the given window. If n is negative,
+    /// then the entire string will be added.
+    ///
+    /// The string is clipped on a character boundary before being handed to curses, so a
+    /// multi-byte character is never partially written.
+    pub fn mvaddnstr<T: AsRef<str>>(&self, y: i32, x: i32, string: T, n: i32) -> i32 {
+        if self.mv(y, x) == ERR {
+            return ERR;
+        }
+        self.addstr(clip_to_chars(string.as_ref(), n))
+    }
+
+    /// Moves the cursor and changes the attributes of a given number of characters starting at the
+    /// cursor location. It does not update the cursor and does not perform wrapping. A character count
+    /// of -1 or greater than the remaining window width means to change attributes all the way
+    /// to the end of the current line. As with chgat(), a color_pair of 0 keeps the pair set in
+    /// attributes.
+    ///
+    /// ```rust
+    /// use pancurses::{initscr, endwin, has_colors, init_pair, start_color, Attribute, Attributes};
+    /// use pancurses::{ColorPair, COLOR_BLACK, COLOR_RED, OK};
+    /// let window = initscr();
+    /// window.mvaddstr(0, 0, "Highlighted");
+    /// assert_eq!(window.mvchgat(0, 0, -1, Attribute::Reverse, 0), OK);
+    /// assert!(Attributes::from(window.mvinch(0, 5)).is_reverse());
+    /// if has_colors() {
+    ///     start_color();
+    ///     init_pair(2, COLOR_RED, COLOR_BLACK);
+    ///     let bold_red = Attributes::from(Attribute::Bold) | ColorPair(2);
+    ///     window.mvchgat(0, 0, -1, bold_red, 0);
+    ///     let cell = Attributes::from(window.mvinch(0, 5));
+    ///     assert!(cell.is_bold());
+    ///     assert_eq!(ColorPair(2), cell.color_pair());
+    /// }
+    /// endwin();
+    /// ```
+    pub fn mvchgat<T: Into<chtype>>(
+        &self,
+        y: i32,
+        x: i32,
+        n: i32,
+        attributes: T,
+        color_pair: i16,
+    ) -> i32 {
+        let (attributes, color_pair) = chgat_pair(attributes.into(), color_pair);
+        unsafe { curses::mvwchgat(self._window, y, x, n, attributes, color_pair, ptr::null_mut()) }
+    }
+
+    /// Moves a derived window (or subwindow) inside its parent window.
+    ///
+    /// The screen-relative parameters of the window are not changed. This routine is used to
+    /// display different parts of the parent window at the same physical position on the screen.
+    ///
+    /// ```rust
+    /// use pancurses::{initscr, endwin, newwin, A_CHARTEXT, OK};
+    /// let window = initscr();
+    /// let parent = newwin(10, 20, 0, 0);
+    /// parent.mvaddstr(6, 7, "moved");
+    /// let child = parent.derwin(2, 10, 0, 0).unwrap();
+    /// assert_eq!(OK, child.mvderwin(6, 7));
+    /// assert_eq!('m' as u32, child.mvinch(0, 0) & A_CHARTEXT);
+    /// endwin();
+    /// ```
+    pub fn mvderwin(&self, pary: i32, parx: i32) -> i32 {
+        unsafe { curses::mvderwin(self._window, pary, parx) }
+    }
+
+    /// Moves the cursor and then reads a line of input as getnstr() does.
+    pub fn mvgetnstr(&self, y: i32, x: i32, max: usize) -> Result<String, i32> {
+        if self.mv(y, x) == ERR {
+            return Err(ERR);
+        }
+        self.getnstr(max)
+    }
+
+    /// Moves the cursor and draws a horizontal line using ch. The line is at most n characters
+    /// long, or as many as fit into the window. The cursor is left at (y, x).
+    ///
+    /// ```rust
+    /// use pancurses::{initscr, endwin, A_BOLD, A_CHARTEXT};
+    /// let window = initscr();
+    /// window.mvhline(1, 2, '-' as u32 | A_BOLD, 1000);
+    /// let max_x = window.get_max_x();
+    /// assert_eq!('-' as u32, window.mvinch(1, max_x - 1) & A_CHARTEXT);
+    /// assert_eq!(A_BOLD, window.mvinch(1, 2) & A_BOLD);
+    /// assert_eq!(' ' as u32, window.mvinch(1, 1) & A_CHARTEXT);
+    /// endwin();
+    /// ```
+    pub fn mvhline<T: ToChtype>(&self, y: i32, x: i32, ch: T, n: i32) -> i32 {
+        unsafe { curses::mvwhline(self._window, y, x, ch.to_chtype(), n) }
+    }
+
+    /// Moves the cursor and reads back the character there together with any combining
+    /// characters, as drawn by add_wch(). Returns None if the position is outside the window.
+    ///
+    /// Without the wide feature ncurses can only report a single 8 bit character. Combining
+    /// characters are only kept by curses when the locale uses a Unicode encoding.
+    ///
+    /// ```rust
+    /// use pancurses::{initscr, endwin, Attribute, ComplexChar, OK};
+    /// let window = initscr();
+    /// let e_acute = ComplexChar::new('é', Attribute::Bold);
+    /// if cfg!(feature = "wide") {
+    ///     assert_eq!(OK, window.mvadd_wch(1, 1, &e_acute));
+    ///     assert_eq!(Some(e_acute), window.mvin_wch(1, 1));
+    /// }
+    /// assert_eq!(None, window.mvin_wch(-1, 0));
+    /// endwin();
+    /// ```
+    pub fn mvin_wch(&self, y: i32, x: i32) -> Option<ComplexChar> {
+        platform_specific::_mvin_wch(self._window, y, x)
+    }
+
+    /// Retrieves the character and attribute from the specified window position, in the form of a
+    /// chtype.
+    pub fn mvinch(&self, y: i32, x: i32) -> chtype {
+        unsafe { curses::mvwinch(self._window, y, x) }
+    }
+
+    /// Moves the cursor and then reads the cells up to the end of the line. An empty vector is
+    /// returned if the position is outside the window.
+    pub fn mvinchstr(&self, y: i32, x: i32) -> Vec<chtype> {
+        if self.mv(y, x) == ERR {
+            return Vec::new();
+        }
+        self.inchstr()
+    }
+
+    /// Moves the cursor and then reads at most n cells, stopping at the end of the line. An
+    /// empty vector is returned if the position is outside the window.
+    ///
+    /// ```rust
+    /// use pancurses::{initscr, endwin, Attributes, A_BOLD, A_CHARTEXT};
+    /// let window = initscr();
+    /// window.mvaddstr(0, 0, "ab");
+    /// window.mvaddch(0, 2, 'c' as u32 | A_BOLD);
+    /// let cells = window.mvinchnstr(0, 0, 3);
+    /// assert_eq!(3, cells.len());
+    /// assert_eq!('c' as u32, cells[2] & A_CHARTEXT);
+    /// assert!(Attributes::from(cells[2]).is_bold());
+    /// endwin();
+    /// ```
+    pub fn mvinchnstr(&self, y: i32, x: i32, n: i32) -> Vec<chtype> {
+        if self.mv(y, x) == ERR {
+            return Vec::new();
+        }
+        self.inchnstr(n)
+    }
+
+    /// Moves the cursor and then reads at most n characters from the window, stopping at the end
+    /// of the line. Trailing blanks are stripped from the result, and an empty string is returned
+    /// if the position is outside the window.
+    ///
+    /// ```rust
+    /// use pancurses::{initscr, endwin};
+    /// let window = initscr();
+    /// window.mvaddstr(1, 2, "Hello world");
+    /// assert_eq!("Hello", window.mvinnstr(1, 2, 5));
+    /// assert_eq!("world", window.mvinstr(1, 8));
+    /// endwin();
+    /// ```
+    pub fn mvinnstr(&self, y: i32, x: i32, n: i32) -> String {
+        if self.mv(y, x) == ERR {
+            return String::new();
+        }
+        self.innstr(n)
     }
 
     /// Move the cursor and then insert the character ch before the character under the cursor.
@@ -442,10 +1433,54 @@ impl Window {
         unsafe { curses::mvwinsch(self._window, y, x, ch.to_chtype()) }
     }
 
+    /// Moves the cursor and then reads the characters up to the end of the line. Trailing blanks
+    /// are stripped from the result, and an empty string is returned if the position is outside
+    /// the window.
+    pub fn mvinstr(&self, y: i32, x: i32) -> String {
+        if self.mv(y, x) == ERR {
+            return String::new();
+        }
+        self.instr()
+    }
+
+    /// Move the cursor and then insert the string before the character under the cursor.
+    ///
+    /// ```rust
+    /// use pancurses::{initscr, endwin, A_CHARTEXT};
+    /// let window = initscr();
+    /// window.mvaddstr(0, 0, "held");
+    /// window.mvinsstr(0, 2, "llo wor");
+    /// let line: String = (0..11)
+    ///     .map(|x| (window.mvinch(0, x) & A_CHARTEXT) as u8 as char)
+    ///     .collect();
+    /// assert_eq!("hello world", line);
+    /// endwin();
+    /// ```
+    pub fn mvinsstr<T: AsRef<str>>(&self, y: i32, x: i32, string: T) -> i32 {
+        let s = CString::new(string.as_ref()).unwrap();
+        unsafe { curses::mvwinsstr(self._window, y, x, s.as_ptr()) }
+    }
+
+    /// Move the cursor and then insert at most n characters of the string before the character
+    /// under the cursor. If n is negative, then the entire string will be inserted.
+    pub fn mvinsnstr<T: AsRef<str>>(&self, y: i32, x: i32, string: T, n: i32) -> i32 {
+        let clipped = clip_to_chars(string.as_ref(), n);
+        let s = CString::new(clipped).unwrap();
+        unsafe { curses::mvwinsnstr(self._window, y, x, s.as_ptr(), clipped.len() as i32) }
+    }
+
     /// Add a string to the window at the specified cursor position.
+    ///
+    /// The string is written as-is rather than being used as a C format string, so `%` needs
+    /// no escaping. Use the mvprintw! macro to format values into the string.
     pub fn mvprintw<T: AsRef<str>>(&self, y: i32, x: i32, string: T) -> i32 {
-        let s = CString::new(string.as_ref()).unwrap();
-        unsafe { curses::mvwprintw(self._window, y, x, s.as_ptr()) }
+        self.mvaddstr(y, x, string)
+    }
+
+    /// Moves the cursor and draws a vertical line using ch. The line is at most n characters
+    /// long, or as many as fit into the window. The cursor is left at (y, x).
+    pub fn mvvline<T: ToChtype>(&self, y: i32, x: i32, ch: T, n: i32) -> i32 {
+        unsafe { curses::mvwvline(self._window, y, x, ch.to_chtype(), n) }
     }
 
     /// Moves the window so that the upper left-hand corner is at position (y,x).
@@ -470,22 +1505,159 @@ impl Window {
 
     /// Overlays this window on top of destination_window. This window and destination_window are
     /// not required to be the same size; only text where the two windows overlap is copied.
-    /// overlay() is non-destructive.
+    /// overlay() is non-destructive: blanks in this window leave the destination untouched.
+    ///
+    /// ```rust
+    /// use pancurses::{initscr, endwin, newwin, A_CHARTEXT};
+    /// let window = initscr();
+    /// let toast = newwin(1, 5, 0, 0);
+    /// let destination = newwin(1, 5, 0, 0);
+    /// toast.mvaddstr(0, 0, "a c");
+    /// destination.mvaddstr(0, 0, "xyz");
+    /// toast.overlay(&destination);
+    /// assert_eq!('y' as u32, destination.mvinch(0, 1) & A_CHARTEXT);
+    /// toast.overwrite(&destination);
+    /// assert_eq!(' ' as u32, destination.mvinch(0, 1) & A_CHARTEXT);
+    /// endwin();
+    /// ```
     pub fn overlay(&self, destination_window: &Window) -> i32 {
         unsafe { curses::overlay(self._window, destination_window._window) }
     }
 
-    /// Overlays this window on top of destination_window. This window and destination_window are
+    /// Copies this window on top of destination_window. This window and destination_window are
     /// not required to be the same size; only text where the two windows overlap is copied.
-    /// overwrite() is destructive.
+    /// overwrite() is destructive: blanks in this window are copied as well.
     pub fn overwrite(&self, destination_window: &Window) -> i32 {
         unsafe { curses::overwrite(self._window, destination_window._window) }
     }
 
+    /// Adds the character ch to the pad and refreshes it immediately, with the same viewport as
+    /// the last prefresh() call. This is considerably faster than calling addch() followed by
+    /// prefresh().
+    pub fn pechochar<T: ToChtype>(&self, ch: T) -> i32 {
+        unsafe { curses::pechochar(self._window, ch.to_chtype()) }
+    }
+
+    /// Like prefresh(), but only copies the pad to the virtual screen without updating the
+    /// physical terminal. Call doupdate() once all pads and windows have been copied.
+    pub fn pnoutrefresh(
+        &self,
+        pminrow: i32,
+        pmincol: i32,
+        sminrow: i32,
+        smincol: i32,
+        smaxrow: i32,
+        smaxcol: i32,
+    ) -> i32 {
+        unsafe {
+            curses::pnoutrefresh(
+                self._window,
+                pminrow,
+                pmincol,
+                sminrow,
+                smincol,
+                smaxrow,
+                smaxcol,
+            )
+        }
+    }
+
+    /// Displays part of a pad on the screen. Only valid on windows created with newpad().
+    ///
+    /// pminrow and pmincol give the upper left corner of the pad region to display, while
+    /// sminrow, smincol, smaxrow and smaxcol give the screen rectangle it is displayed in.
+    pub fn prefresh(
+        &self,
+        pminrow: i32,
+        pmincol: i32,
+        sminrow: i32,
+        smincol: i32,
+        smaxrow: i32,
+        smaxcol: i32,
+    ) -> i32 {
+        unsafe {
+            curses::prefresh(
+                self._window,
+                pminrow,
+                pmincol,
+                sminrow,
+                smincol,
+                smaxrow,
+                smaxcol,
+            )
+        }
+    }
+
+    /// Saves the window's size, contents and attributes to the file at path, so that it can be
+    /// recreated later with getwin().
+    pub fn putwin<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let file = fopen(path.as_ref(), "wb")?;
+        let result = unsafe { curses::putwin(self._window, file as _) };
+        let closed = unsafe { libc::fclose(file) };
+        if result == ERR {
+            Err(io::Error::other("putwin failed"))
+        } else if closed != 0 {
+            Err(io::Error::last_os_error())
+        } else {
+            Ok(())
+        }
+    }
+
     /// Add a string to the window at the current cursor position.
+    ///
+    /// The string is written as-is rather than being used as a C format string, so `%` needs
+    /// no escaping. Use the printw! macro to format values into the string.
     pub fn printw<T: AsRef<str>>(&self, string: T) -> i32 {
-        let s = CString::new(string.as_ref()).unwrap();
-        unsafe { curses::wprintw(self._window, s.as_ptr()) }
+        self.addstr(string)
+    }
+
+    /// Reads the cell at the given position, split into its character, attributes and color
+    /// pair, or None if the position is outside the window. The cursor is moved to the position.
+    ///
+    /// On wide builds the cell is read with mvwin_wch() so non-ASCII characters come back
+    /// intact, and the pair is the whole extended pair number rather than the 8 bits a chtype
+    /// can hold.
+    ///
+    /// ```rust
+    /// use pancurses::{initscr, endwin, has_colors, init_extended_pair, start_color};
+    /// use pancurses::{A_NORMAL, A_UNDERLINE, COLOR_BLACK, COLOR_PAIR, COLOR_RED, OK};
+    /// let window = initscr();
+    /// window.mvaddch(1, 1, 'q' as u32 | A_UNDERLINE | COLOR_PAIR(3));
+    /// let cell = window.read_cell(1, 1).unwrap();
+    /// assert_eq!('q', cell.ch);
+    /// assert!(cell.attributes.is_underline());
+    /// assert_eq!(3, cell.color_pair);
+    /// assert_eq!(None, window.read_cell(-1, 0));
+    ///
+    /// // Only terminals with more than 256 pairs and ncurses with extended pairs can do this
+    /// if has_colors() {
+    ///     start_color();
+    /// }
+    /// if init_extended_pair(300, COLOR_RED.into(), COLOR_BLACK.into()) == OK {
+    ///     window.attr_set_extended(A_NORMAL, 300);
+    ///     window.mvaddstr(2, 0, "x");
+    ///     assert_eq!(300, window.read_cell(2, 0).unwrap().color_pair);
+    /// }
+    /// endwin();
+    /// ```
+    pub fn read_cell(&self, y: i32, x: i32) -> Option<Cell> {
+        platform_specific::_mvin_cell(self._window, y, x)
+    }
+
+    /// Tells curses that num_lines lines starting at beg_line are corrupted on the physical
+    /// screen and must be redrawn completely on the next refresh.
+    ///
+    /// Unlike touchline() this also discards what curses believes is on the terminal, which is
+    /// what's needed after another program has written over the screen.
+    pub fn redrawln(&self, beg_line: i32, num_lines: i32) -> i32 {
+        unsafe { curses::wredrawln(self._window, beg_line, num_lines) }
+    }
+
+    /// Tells curses that the whole window is corrupted on the physical screen and must be
+    /// redrawn completely on the next refresh, for example after returning from a shelled-out
+    /// command with reset_prog_mode().
+    pub fn redrawwin(&self) -> i32 {
+        unsafe { curses::redrawwin(self._window) }
     }
 
     /// Copies the named window to the physical terminal screen, taking into account what
@@ -494,16 +1666,60 @@ impl Window {
     /// This function must be called to get any output on the terminal, as other routines only
     /// manipulate data structures. Unless leaveok() has been enabled, the physical cursor of the
     /// terminal is left at the location of the window's cursor.
+    ///
+    /// Pads must not be refreshed this way; use prefresh() instead.
     pub fn refresh(&self) -> i32 {
         unsafe { curses::wrefresh(self._window) }
     }
     
-    /// Resizes the window to the given dimensions. Doesn't resize subwindows on pdcurses
-    /// so you have to resize them yourself.
+    /// Resizes the window to the given dimensions, keeping as much of its contents as fits.
+    ///
+    /// This is not the same as the global resize_term(), which resizes the curses screen itself.
+    /// Doesn't resize subwindows on pdcurses so you have to resize them yourself; shrink
+    /// subwindows before their parent and grow them after it so they always fit inside it.
+    ///
+    /// ```rust
+    /// use pancurses::{initscr, endwin, newwin, A_CHARTEXT, OK};
+    /// let window = initscr();
+    /// let mut subject = newwin(10, 20, 0, 0);
+    /// subject.mvaddstr(1, 1, "kept");
+    /// subject.mvaddstr(8, 1, "lost");
+    /// assert_eq!(OK, subject.resize(5, 10));
+    /// assert_eq!((5, 10), subject.get_max_yx());
+    /// assert_eq!(OK, subject.resize(10, 20));
+    /// assert_eq!('k' as u32, subject.mvinch(1, 1) & A_CHARTEXT);
+    /// assert_eq!(' ' as u32, subject.mvinch(8, 1) & A_CHARTEXT);
+    /// endwin();
+    /// ```
     pub fn resize(&mut self, nlines: i32, ncols: i32) -> i32 {
         unsafe { curses::wresize(self._window, nlines, ncols) }
     }
 
+    /// Scrolls the scrolling region of the window up by n lines, or down if n is negative. The
+    /// lines scrolled in are blank.
+    ///
+    /// Scrolling only happens if it has been enabled with scrollok(); otherwise ERR is returned.
+    ///
+    /// ```rust
+    /// use pancurses::{initscr, endwin, newwin, A_CHARTEXT};
+    /// let window = initscr();
+    /// let log = newwin(6, 10, 0, 0);
+    /// for line in 0..6 {
+    ///     log.mvaddstr(line, 0, line.to_string());
+    /// }
+    /// log.scrollok(true);
+    /// log.setscrreg(1, 4);
+    /// log.scrl(2);
+    /// let first_chars: String = (0..6)
+    ///     .map(|y| (log.mvinch(y, 0) & A_CHARTEXT) as u8 as char)
+    ///     .collect();
+    /// assert_eq!("034  5", first_chars);
+    /// endwin();
+    /// ```
+    pub fn scrl(&self, n: i32) -> i32 {
+        unsafe { curses::wscrl(self._window, n) }
+    }
+
     /// If enabled and a scrolling region is set with setscrreg(), any attempt to move off
     /// the bottom margin will cause all lines in the scrolling region to scroll up one line.
     pub fn scrollok(&self, bf: bool) -> i32 {
@@ -512,7 +1728,9 @@ impl Window {
 
     /// Sets a scrolling region in a window.
     ///
-    /// "top" and "bot" are the line numbers for the top and bottom margins.
+    /// "top" and "bot" are the window-relative line numbers for the top and bottom margins.
+    /// Lines outside the region stay fixed when the window scrolls, provided scrolling has been
+    /// enabled with scrollok().
     pub fn setscrreg(&self, top: i32, bot: i32) -> i32 {
         unsafe { curses::wsetscrreg(self._window, top, bot) }
     }
@@ -535,6 +1753,115 @@ impl Window {
         }
     }
 
+    /// Copies the contents of the window into a ScreenBuffer, reading each cell with
+    /// read_cell() so that wide builds keep non-ASCII characters. The cursor position is left
+    /// unchanged.
+    ///
+    /// ```rust
+    /// use pancurses::{initscr, endwin, newwin, A_BOLD};
+    /// let window = initscr();
+    /// let dialog = newwin(3, 12, 0, 0);
+    /// dialog.mvaddstr(1, 1, "Title");
+    /// dialog.mvaddch(1, 7, '!' as u32 | A_BOLD);
+    /// let buffer = dialog.snapshot();
+    /// assert_eq!((3, 12), (buffer.height(), buffer.width()));
+    /// assert_eq!(" Title !", buffer.line(1));
+    /// assert!(buffer.cell(1, 7).unwrap().attributes.is_bold());
+    /// assert_eq!("\n Title !\n", buffer.to_string());
+    ///
+    /// // Borders drawn from the alternate character set come back as box-drawing characters
+    /// let boxed = newwin(3, 7, 3, 0);
+    /// boxed.draw_box(0, 0);
+    /// assert_eq!("┌─────┐", boxed.snapshot().line(0));
+    /// assert_eq!("│     │", boxed.snapshot().line(1));
+    /// endwin();
+    /// ```
+    pub fn snapshot(&self) -> ScreenBuffer {
+        let (cursor_y, cursor_x) = self.get_cur_yx();
+        let (height, width) = self.get_max_yx();
+        let rows = (0..height)
+            .map(|y| (0..width).filter_map(|x| self.read_cell(y, x)).collect())
+            .collect();
+        self.mv(cursor_y, cursor_x);
+        ScreenBuffer::new(rows)
+    }
+
+    /// Turns off all attributes, including the color pair, as a counterpart to standout().
+    pub fn standend(&self) -> i32 {
+        unsafe { curses::wstandend(self._window) }
+    }
+
+    /// Turns on the terminal's best highlighting mode, the same as attron(A_STANDOUT). This works
+    /// on monochrome terminals too, which makes it a good fallback when has_colors() is false.
+    ///
+    /// ```rust
+    /// use pancurses::{initscr, endwin, Attribute};
+    /// let window = initscr();
+    /// window.standout();
+    /// assert!(window.attr_get().0.contains(Attribute::Standout));
+    /// window.standend();
+    /// assert!(window.attr_get().0.is_empty());
+    /// endwin();
+    /// ```
+    pub fn standout(&self) -> i32 {
+        unsafe { curses::wstandout(self._window) }
+    }
+
+    /// Creates a new subpad within a pad.
+    ///
+    /// The dimensions of the subpad are nlines lines and ncols columns. Unlike subwin(), the
+    /// position (begy, begx) is relative to the pad and not to the screen. Changes made to either
+    /// pad will affect both, and the subpad should be dropped before its parent.
+    ///
+    /// ```rust
+    /// use pancurses::{initscr, endwin, newpad, A_CHARTEXT};
+    /// let window = initscr();
+    /// let pad = newpad(100, 40);
+    /// let body = pad.subpad(90, 40, 10, 0).unwrap();
+    /// body.mvaddch(0, 3, 'x');
+    /// assert_eq!('x' as u32, pad.mvinch(10, 3) & A_CHARTEXT);
+    /// endwin();
+    /// ```
+    pub fn subpad(&self, nlines: i32, ncols: i32, begy: i32, begx: i32) -> Result<Window, i32> {
+        let new_window = unsafe { curses::subpad(self._window, nlines, ncols, begy, begx) };
+        if new_window.is_null() {
+            Err(ERR)
+        } else {
+            Ok(Window {
+                _window: new_window,
+                _stdscr: false,
+            })
+        }
+    }
+
+    /// Touches the window if any of its ancestors has been touched.
+    pub fn syncdown(&self) {
+        unsafe { curses::wsyncdown(self._window) }
+    }
+
+    /// Touches every location in the window's ancestors that has been changed in the window.
+    ///
+    /// ```rust
+    /// use pancurses::{initscr, endwin, newwin};
+    /// let window = initscr();
+    /// let parent = newwin(10, 20, 0, 0);
+    /// let child = parent.derwin(5, 10, 2, 2).unwrap();
+    /// parent.untouch();
+    /// child.mvaddstr(0, 0, "changed");
+    /// child.syncup();
+    /// assert!(parent.is_wintouched());
+    /// endwin();
+    /// ```
+    pub fn syncup(&self) {
+        unsafe { curses::wsyncup(self._window) }
+    }
+
+    /// If enabled, syncup() is called automatically whenever the window changes, so that its
+    /// ancestors are touched as well.
+    pub fn syncok(&self, bf: bool) -> i32 {
+        unsafe { curses::syncok(self._window, bf as u8) }
+    }
+
     /// Set blocking or non-blocking reads for the specified window.
     ///
     /// The delay is measured in milliseconds. If it's negative, a blocking read is used; if zero,
@@ -555,12 +1882,25 @@ impl Window {
         unsafe { curses::touchwin(self._window) }
     }
 
-    /// Throws away all information about which parts of the window have been touched, pretending
-    /// that the entire window has been drawn on.
+    /// Pretends that count lines, starting at line start, have been drawn on, so that they are
+    /// sent to the terminal on the next refresh().
     ///
-    ///  This is sometimes necessary when using overlapping windows, since a change to one window
-    /// will affect the other window, but the records of which lines have been changed in the other
-    /// window will not reflect the change.
+    /// Touching only the lines that changed is cheaper than touch() when a subwindow has been
+    /// drawn into and its parent needs refreshing.
+    ///
+    /// ```rust
+    /// use pancurses::{initscr, endwin, newwin};
+    /// let window = initscr();
+    /// let subject = newwin(5, 10, 0, 0);
+    /// subject.untouchwin();
+    /// subject.touchline(1, 2);
+    /// assert!(!subject.is_linetouched(0));
+    /// assert!(subject.is_linetouched(2));
+    /// assert!(subject.is_wintouched());
+    /// subject.touchln(0, 5, false);
+    /// assert!(!subject.is_wintouched());
+    /// endwin();
+    /// ```
     pub fn touchline(&self, start: i32, count: i32) -> i32 {
         unsafe { curses::touchline(self._window, start, count) }
     }
@@ -571,21 +1911,265 @@ impl Window {
         unsafe { curses::wtouchln(self._window, y, n, if changed { 1 } else { 0 }) }
     }
 
+    /// Returns the text of the window as lines() does, joined with newlines. Characters other
+    /// than line-drawing ones that can't be read back are replaced by U+FFFD.
+    pub fn to_string_lossy(&self) -> String {
+        self.lines().collect::<Vec<_>>().join("\n")
+    }
+
+    /// Creates an exact duplicate of the window, including its contents and attributes, or
+    /// returns ERR if curses couldn't allocate it.
+    ///
+    /// ```rust
+    /// use pancurses::{initscr, endwin, newwin, A_CHARTEXT};
+    /// let window = initscr();
+    /// let original = newwin(2, 10, 0, 0);
+    /// original.mvaddstr(0, 0, "before");
+    /// let copy = original.try_clone().unwrap();
+    /// original.mvaddstr(0, 0, "after!");
+    /// assert_eq!('b' as u32, copy.mvinch(0, 0) & A_CHARTEXT);
+    /// drop(original);
+    /// assert_eq!('e' as u32, copy.mvinch(0, 1) & A_CHARTEXT);
+    /// endwin();
+    /// ```
+    pub fn try_clone(&self) -> Result<Window, i32> {
+        let dup_win = unsafe { curses::dupwin(self._window) };
+        if dup_win.is_null() {
+            Err(ERR)
+        } else {
+            Ok(Window {
+                _window: dup_win,
+                _stdscr: false,
+            })
+        }
+    }
+
+    fn check_bounds(&self, y: i32, x: i32) -> Result<(), DrawError> {
+        let (max_y, max_x) = self.get_max_yx();
+        if y < 0 || x < 0 || y >= max_y || x >= max_x {
+            Err(DrawError::OutOfBounds { y, x })
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Reads a key if one is waiting and returns None right away otherwise, without changing the
+    /// delay mode set by nodelay() or timeout(). The previous delay is put back even if reading
+    /// panics, so this is safe to call from a render loop instead of toggling nodelay().
+    ///
+    /// ```rust
+    /// use pancurses::{initscr, endwin, Input};
+    /// let window = initscr();
+    /// window.timeout(250);
+    /// window.ungetch(&Input::KeyLeft);
+    /// assert_eq!(Some(Input::KeyLeft), window.try_getch());
+    /// assert_eq!(None, window.try_getch());
+    /// endwin();
+    /// ```
+    pub fn try_getch(&self) -> Option<Input> {
+        let _non_blocking = NonBlocking::new(self);
+        self.getch()
+    }
+
+    /// Like mvaddch(), but reports a position outside the window as DrawError::OutOfBounds,
+    /// separately from any other failure.
+    pub fn try_mvaddch<T: ToChtype>(&self, y: i32, x: i32, ch: T) -> Result<(), DrawError> {
+        self.check_bounds(y, x)?;
+        draw_result(self.mvaddch(y, x, ch))
+    }
+
+    /// Like mvaddstr(), but reports a starting position outside the window as
+    /// DrawError::OutOfBounds, separately from any other failure. The unchecked mvaddstr() is
+    /// cheaper when the position is already known to be valid.
+    ///
+    /// ```rust
+    /// use pancurses::{initscr, endwin, newwin, DrawError};
+    /// let window = initscr();
+    /// let sub = newwin(2, 4, 0, 0);
+    /// assert_eq!(Ok(()), sub.try_mvaddstr(0, 0, "ok"));
+    /// assert_eq!(Err(DrawError::OutOfBounds { y: 2, x: 0 }), sub.try_mvaddstr(2, 0, "no"));
+    /// assert!(match sub.try_mvaddstr(1, 2, "long") {
+    ///     Err(DrawError::Curses(_)) => true,
+    ///     _ => false,
+    /// });
+    /// endwin();
+    /// ```
+    pub fn try_mvaddstr<T: AsRef<str>>(
+        &self,
+        y: i32,
+        x: i32,
+        string: T,
+    ) -> Result<(), DrawError> {
+        self.check_bounds(y, x)?;
+        draw_result(self.mvaddstr(y, x, string))
+    }
+
     /// Places ch back onto the input queue to be returned by the next call to getch().
+    ///
+    /// Every Input that getch() can return can be pushed back; ERR is returned for keys the
+    /// backend has no code for, such as the PDCurses only KeyAbort on ncurses.
+    ///
+    /// ```rust
+    /// use pancurses::{initscr, endwin, Input};
+    /// let window = initscr();
+    /// for input in &[Input::Character('ä'), Input::KeyDown, Input::KeyF12, Input::KeyResize] {
+    ///     window.ungetch(input);
+    ///     assert_eq!(Some(input.clone()), window.getch());
+    /// }
+    /// endwin();
+    /// ```
     pub fn ungetch(&self, input: &Input) -> i32 {
         platform_specific::_ungetch(input)
     }
 
+    /// Places the character back onto the input queue to be returned by the next call to
+    /// get_wch(). On wide builds this goes through unget_wch(), which needs a locale that can
+    /// encode the character.
+    pub fn unget_wch(&self, ch: char) -> i32 {
+        platform_specific::_unget_wch(ch)
+    }
+
     /// Marks all lines in the window as unchanged since the last call to refresh().
     pub fn untouch(&self) -> i32 {
         unsafe { curses::untouchwin(self._window) }
     }
 
+    /// Same as untouch(), under its curses name.
+    pub fn untouchwin(&self) -> i32 {
+        self.untouch()
+    }
+
     /// Draw a vertical line using ch from the current cursor position. The line is at most
     /// n characters long, or as many as fit into the window.
+    ///
+    /// Attributes can be OR'd into ch to draw a styled line, e.g. `ACS_VLINE() | A_BOLD`.
     pub fn vline<T: ToChtype>(&self, ch: T, n: i32) -> i32 {
         unsafe { curses::wvline(self._window, ch.to_chtype(), n) }
     }
+
+    /// Turns on the given attributes for the duration of the closure f, then restores the
+    /// attributes and color pair that were active before the call.
+    ///
+    /// ```rust
+    /// use pancurses::{initscr, endwin, A_BOLD, A_REVERSE, COLOR_PAIR};
+    /// let window = initscr();
+    /// window.with_attributes(A_BOLD | COLOR_PAIR(1), |w| {
+    ///     w.with_attributes(A_REVERSE | COLOR_PAIR(2), |w| {
+    ///         assert_eq!(2, w.attrget().1);
+    ///     });
+    ///     assert_eq!(1, w.attrget().1);
+    /// });
+    /// assert_eq!((0, 0), window.attrget());
+    /// endwin();
+    /// ```
+    pub fn with_attributes<A, F, R>(&self, attributes: A, f: F) -> R
+    where
+        A: Into<chtype>,
+        F: FnOnce(&Window) -> R,
+    {
+        let (previous_attributes, previous_color_pair) = self.attrget();
+        self.attron(attributes);
+        let result = f(self);
+        self.attr_set(previous_attributes, previous_color_pair);
+        result
+    }
+}
+
+/// Maps the VT100 alternate character set to the Unicode characters it draws.
+pub(crate) fn acs_to_unicode(ch: char) -> char {
+    match ch {
+        'j' => '┘',
+        'k' => '┐',
+        'l' => '┌',
+        'm' => '└',
+        'n' => '┼',
+        'q' => '─',
+        't' => '├',
+        'u' => '┤',
+        'v' => '┴',
+        'w' => '┬',
+        'x' => '│',
+        '`' => '◆',
+        'a' => '▒',
+        'f' => '°',
+        'g' => '±',
+        '~' => '·',
+        ',' => '←',
+        '+' => '→',
+        '.' => '↓',
+        '-' => '↑',
+        'h' => '░',
+        'i' => '␋',
+        '0' => '█',
+        'o' => '⎺',
+        'p' => '⎻',
+        'r' => '⎼',
+        's' => '⎽',
+        'y' => '≤',
+        'z' => '≥',
+        '{' => 'π',
+        '|' => '≠',
+        '}' => '£',
+        other => other,
+    }
+}
+
+/// Switches a window to non-blocking reads for as long as it's alive, then restores the delay
+/// the window had before, also when unwinding from a panic.
+struct NonBlocking<'a> {
+    window: &'a Window,
+    delay: i32,
+}
+
+impl<'a> NonBlocking<'a> {
+    fn new(window: &'a Window) -> NonBlocking<'a> {
+        let delay = platform_specific::_getdelay(window._window);
+        window.timeout(0);
+        NonBlocking { window, delay }
+    }
+}
+
+impl<'a> Drop for NonBlocking<'a> {
+    fn drop(&mut self) {
+        self.window.timeout(self.delay);
+    }
+}
+
+fn draw_result(rc: i32) -> Result<(), DrawError> {
+    if rc == ERR {
+        Err(DrawError::Curses(rc))
+    } else {
+        Ok(())
+    }
+}
+
+/// Returns the longest prefix of string that holds at most n characters, or all of it if n is
+/// negative.
+/// Splits attributes given to chgat() into the attributes and pair curses takes, using the pair in
+/// the attributes unless color_pair picks another one.
+fn chgat_pair(attributes: chtype, color_pair: i16) -> (chtype, i16) {
+    let color_pair = if color_pair == 0 { PAIR_NUMBER(attributes) as i16 } else { color_pair };
+    (attributes & !A_COLOR, color_pair)
+}
+
+fn clip_to_chars(string: &str, n: i32) -> &str {
+    if n < 0 {
+        return string;
+    }
+    match string.char_indices().nth(n as usize) {
+        Some((index, _)) => &string[..index],
+        None => string,
+    }
+}
+
+/// Returns the number of chtypes to copy from chstr: at most n, or all of them if n is negative.
+fn clip_to_slice(chstr: &[chtype], n: i32) -> i32 {
+    let length = chstr.len().min(i32::MAX as usize) as i32;
+    if n < 0 {
+        length
+    } else {
+        n.min(length)
+    }
 }
 
 pub fn new_window(window_pointer: WindowPointer, is_stdscr: bool) -> Window {
@@ -6,6 +6,10 @@ pub fn COLOR_PAIR(n: chtype) -> chtype {
     (n << PDC_COLOR_SHIFT) & A_COLOR
 }
 
+pub fn PAIR_NUMBER(n: chtype) -> chtype {
+    (n & A_COLOR) >> PDC_COLOR_SHIFT
+}
+
 pub const COLOR_BLACK: i16 = 0;
 pub const COLOR_RED: i16 = 1;
 pub const COLOR_GREEN: i16 = 2;
@@ -19,23 +23,31 @@ pub const PDC_CHARTEXT_BITS: chtype = 21;
 pub const PDC_COLOR_SHIFT: chtype = PDC_CHARTEXT_BITS + 12;
 
 pub const A_ALTCHARSET: chtype = 0x001 << PDC_CHARTEXT_BITS;
+pub const A_ATTRIBUTES: chtype = !A_CHARTEXT;
 pub const A_BOLD: chtype = 0x080 << PDC_CHARTEXT_BITS;
 pub const A_BLINK: chtype = 0x040 << PDC_CHARTEXT_BITS;
 pub const A_COLOR: chtype = 0x7fffffff << PDC_COLOR_SHIFT;
 pub const A_CHARTEXT: chtype = (0x1 << PDC_CHARTEXT_BITS) - 1;
 pub const A_DIM: chtype = 0x400 << PDC_CHARTEXT_BITS;
+pub const A_HORIZONTAL: chtype = 0; // Not supported on PDCurses
 pub const A_LEFTLINE: chtype = 0x004 << PDC_CHARTEXT_BITS;
 pub const A_INVIS: chtype = 0x008 << PDC_CHARTEXT_BITS;
 pub const A_ITALIC: chtype = A_INVIS;
+pub const A_LOW: chtype = 0; // Not supported on PDCurses
 pub const A_NORMAL: chtype = 0;
 pub const A_OVERLINE: chtype = 0x100 << PDC_CHARTEXT_BITS;
+pub const A_PROTECT: chtype = A_UNDERLINE | A_LEFTLINE | A_RIGHTLINE;
 pub const A_REVERSE: chtype = 0x020 << PDC_CHARTEXT_BITS;
 pub const A_RIGHTLINE: chtype = 0x002 << PDC_CHARTEXT_BITS;
+pub const A_STANDOUT: chtype = A_REVERSE | A_BOLD;
 pub const A_STRIKEOUT: chtype = 0x200 << PDC_CHARTEXT_BITS;
+pub const A_TOP: chtype = 0; // Not supported on PDCurses
 pub const A_UNDERLINE: chtype = 0x010 << PDC_CHARTEXT_BITS;
+pub const A_VERTICAL: chtype = 0; // Not supported on PDCurses
 
 pub const KEY_OFFSET: i32 = 0xec00;
 pub const KEY_F15: i32 = KEY_OFFSET + 0x17;
+pub const KEY_F63: i32 = KEY_OFFSET + 0x47;
 pub const KEY_UNDO: i32 = KEY_OFFSET + 0x96;
 pub const KEY_RESIZE: i32 = KEY_OFFSET + 0x122;
 pub const KEY_MOUSE: i32 = KEY_OFFSET + 0x11b;
@@ -57,6 +69,19 @@ pub const KEY_NUMPAD_MINUS: i32 = 60624;
 pub const KEY_NUMPAD_ASTERISK: i32 = 60623;
 pub const KEY_NUMPAD_SLASH: i32 = 60618;
 
+pub const CTL_LEFT: i32 = KEY_OFFSET + 0x1bb;
+pub const CTL_RIGHT: i32 = KEY_OFFSET + 0x1bc;
+pub const CTL_PGUP: i32 = KEY_OFFSET + 0x1bd;
+pub const CTL_PGDN: i32 = KEY_OFFSET + 0x1be;
+pub const CTL_HOME: i32 = KEY_OFFSET + 0x1bf;
+pub const CTL_END: i32 = KEY_OFFSET + 0x1c0;
+pub const CTL_UP: i32 = KEY_OFFSET + 0x1e0;
+pub const CTL_DOWN: i32 = KEY_OFFSET + 0x1e1;
+
+pub const PDC_KEY_MODIFIER_SHIFT: u32 = 1;
+pub const PDC_KEY_MODIFIER_CONTROL: u32 = 2;
+pub const PDC_KEY_MODIFIER_ALT: u32 = 4;
+
 pub const SPECIAL_KEY_CODES: [Input; 102] = [
     Input::KeyCodeYes,
     Input::KeyBreak,
@@ -2,15 +2,20 @@
 use pdcurses::*;
 use libc::c_int;
 
+use std::convert::TryFrom;
 use std::ffi::{CStr, CString};
 use std::char::decode_utf16;
 use std::iter;
 use std::cmp;
+use std::ptr;
 
 pub mod constants;
 use self::constants::*;
 
 use input::Input;
+use keyevent::KeyEvent;
+use complexchar::ComplexChar;
+use screenbuffer::Cell;
 
 #[cfg(any(feature = "win32a", all(not(feature = "win32"), not(feature = "win32a"))))]
 mod win32a;
@@ -24,6 +29,25 @@ use self::win32 as flavor;
 
 pub use self::flavor::pre_init;
 
+pub fn _add_wch(w: *mut WINDOW, ch: &ComplexChar) -> i32 {
+    // PDCurses keeps the whole character in a chtype but has nowhere to put combining
+    // characters, so only the base character is drawn
+    unsafe { waddch(w, ch.base() as chtype | chtype::from(ch.attributes())) }
+}
+
+pub fn _addstr(w: *mut WINDOW, string: &str) -> i32 {
+    // PDCurses decodes UTF-8 itself, keeping each character in a single cell
+    let s = CString::new(string).unwrap();
+    unsafe { waddstr(w, s.as_ptr()) }
+}
+
+#[cfg(feature = "wide")]
+pub fn _addwstr(w: *mut WINDOW, string: &str) -> i32 {
+    // PDCurses takes UTF-8 through waddstr() directly
+    let s = CString::new(string).unwrap();
+    unsafe { waddstr(w, s.as_ptr()) }
+}
+
 pub fn _attron(w: *mut WINDOW, attributes: chtype) -> i32 {
     unsafe { wattron(w, attributes) }
 }
@@ -36,6 +60,33 @@ pub fn _attrset(w: *mut WINDOW, attributes: chtype) -> i32 {
     unsafe { wattrset(w, attributes) }
 }
 
+pub fn _attr_on(w: *mut WINDOW, attributes: chtype) -> i32 {
+    _attron(w, attributes)
+}
+
+pub fn _attr_off(w: *mut WINDOW, attributes: chtype) -> i32 {
+    _attroff(w, attributes)
+}
+
+pub fn _attr_set(w: *mut WINDOW, attributes: chtype, color_pair: i16) -> i32 {
+    _attrset(w, attributes | COLOR_PAIR(color_pair as chtype))
+}
+
+pub fn _attr_set_extended(w: *mut WINDOW, attributes: chtype, color_pair: i32) -> i32 {
+    // Only pairs that fit in the chtype's color bits can be selected
+    match i16::try_from(color_pair) {
+        Ok(color_pair) => _attr_set(w, attributes, color_pair),
+        Err(_) => ::ERR,
+    }
+}
+
+pub fn _color_set_extended(w: *mut WINDOW, color_pair: i32) -> i32 {
+    match i16::try_from(color_pair) {
+        Ok(color_pair) => unsafe { wcolor_set(w, color_pair, ptr::null_mut()) },
+        Err(_) => ::ERR,
+    }
+}
+
 pub fn _COLORS() -> i32 {
     unsafe { COLORS }
 }
@@ -64,6 +115,136 @@ pub fn _getmouse() -> Result<MEVENT, i32> {
     }
 }
 
+pub fn _get_key_event(w: *mut WINDOW) -> Option<KeyEvent> {
+    unsafe { PDC_return_key_modifiers(1) };
+    let i = unsafe { wgetch(w) };
+    if i < 0 {
+        return None;
+    }
+    // PDCurses has codes of its own for some Ctrl combinations
+    let key = match i {
+        CTL_LEFT => Input::KeyLeft,
+        CTL_RIGHT => Input::KeyRight,
+        CTL_UP => Input::KeyUp,
+        CTL_DOWN => Input::KeyDown,
+        CTL_HOME => Input::KeyHome,
+        CTL_END => Input::KeyEnd,
+        CTL_PGUP => Input::KeyPPage,
+        CTL_PGDN => Input::KeyNPage,
+        _ => decode_input(w, i),
+    };
+    let modifiers = unsafe { PDC_get_key_modifiers() } as u32;
+    let mut event = KeyEvent::from_shifted(key);
+    event.shift |= modifiers & PDC_KEY_MODIFIER_SHIFT != 0;
+    event.ctrl |= modifiers & PDC_KEY_MODIFIER_CONTROL != 0;
+    event.alt |= modifiers & PDC_KEY_MODIFIER_ALT != 0;
+    Some(event)
+}
+
+pub fn _get_escdelay() -> i32 {
+    ::ERR // Not supported
+}
+
+/// Returns the window's input delay in the terms timeout() takes it.
+pub fn _getdelay(w: *mut WINDOW) -> i32 {
+    // PDCurses keeps nodelay separately from the timeout, with a zero timeout meaning none
+    unsafe {
+        if (*w)._nodelay != 0 {
+            0
+        } else if (*w)._delayms == 0 {
+            -1
+        } else {
+            (*w)._delayms
+        }
+    }
+}
+
+pub fn _getnstr(w: *mut WINDOW, max: usize) -> Result<String, i32> {
+    let n = cmp::min(max, c_int::MAX as usize - 1);
+    let mut buffer = vec![0u8; n + 1];
+    if unsafe { wgetnstr(w, buffer.as_mut_ptr() as *mut _, n as c_int) } == ::ERR {
+        return Err(::ERR);
+    }
+    let length = buffer.iter().position(|&b| b == 0).unwrap_or(n);
+    buffer.truncate(length);
+    Ok(String::from_utf8_lossy(&buffer).into_owned())
+}
+
+pub fn _innstr(w: *mut WINDOW, n: i32) -> String {
+    // PDCurses reads to the end of the line for a negative n, so that's what the buffer is for
+    let n = if n < 0 {
+        cmp::max(unsafe { getmaxx(w) - getcurx(w) }, 0)
+    } else {
+        n
+    };
+    let mut buffer = vec![0u8; n as usize + 1];
+    let length = unsafe { winnstr(w, buffer.as_mut_ptr() as *mut _, n) };
+    buffer.truncate(cmp::max(length, 0) as usize);
+    String::from_utf8_lossy(&buffer).into_owned()
+}
+
+pub fn _mvadd_wch(w: *mut WINDOW, y: i32, x: i32, ch: &ComplexChar) -> i32 {
+    unsafe { mvwaddch(w, y, x, ch.base() as chtype | chtype::from(ch.attributes())) }
+}
+
+pub fn _mvin_cell(w: *mut WINDOW, y: i32, x: i32) -> Option<Cell> {
+    let ch = unsafe { mvwinch(w, y, x) };
+    if ch == ::ERR as chtype {
+        None
+    } else {
+        Some(Cell::from(ch))
+    }
+}
+
+pub fn _mvin_wch(w: *mut WINDOW, y: i32, x: i32) -> Option<ComplexChar> {
+    _mvin_cell(w, y, x).map(ComplexChar::from)
+}
+
+pub fn _alloc_pair(_: i32, _: i32) -> i32 {
+    ::ERR // Not supported
+}
+
+pub fn _extended_pair_content(_: i32) -> Option<(i32, i32)> {
+    None // Not supported
+}
+
+pub fn _find_pair(_: i32, _: i32) -> i32 {
+    ::ERR // Not supported
+}
+
+pub fn _free_pair(_: i32) -> i32 {
+    ::ERR // Not supported
+}
+
+pub fn _define_key(_: &str, _: i32) -> i32 {
+    ::ERR // Not supported
+}
+
+pub fn _has_key(input: &Input) -> bool {
+    // PDCurses' has_key() accepts any code in its key range, so this is equivalent
+    match *input {
+        Input::Character(_) | Input::Unknown(_) => false,
+        ref special => to_keycode(special).is_some(),
+    }
+}
+
+pub fn _has_mouse() -> bool {
+    true // The console always delivers mouse input to PDCurses
+}
+
+pub fn _init_extended_pair(_: i32, _: i32, _: i32) -> i32 {
+    ::ERR // Not supported
+}
+
+pub fn _is_term_resized(_: i32, _: i32) -> bool {
+    // PDCurses can only tell whether the user has resized the console
+    unsafe { is_termresized() != 0 }
+}
+
+pub fn _key_defined(_: &str) -> i32 {
+    0 // Not supported, so nothing is ever defined
+}
+
 pub fn _keyname(code: i32) -> Option<String> {
     let ptr = unsafe { keyname(code) };
     if ptr.is_null() {
@@ -78,14 +259,52 @@ pub fn _keyname(code: i32) -> Option<String> {
     }
 }
 
+pub fn _on_resize() -> (i32, i32) {
+    unsafe {
+        resize_term(0, 0);
+        (LINES, COLS)
+    }
+}
+
+pub fn _reset_color_pairs() -> i32 {
+    ::ERR // Not supported
+}
+
+pub fn _resize_to_terminal() -> Option<(i32, i32)> {
+    unsafe {
+        if is_termresized() == 0 || resize_term(0, 0) == ::ERR {
+            None
+        } else {
+            Some((LINES, COLS))
+        }
+    }
+}
+
 pub fn _resize_term(nlines: i32, ncols: i32) -> i32 {
     unsafe { resize_term(nlines, ncols) }
 }
 
+pub fn _resizeterm(lines: i32, cols: i32) -> i32 {
+    // PDCurses' resize_term() already keeps the contents of windows
+    unsafe { resize_term(lines, cols) }
+}
+
 pub fn _set_blink(enabled: bool) -> i32 {
     unsafe { PDC_set_blink(enabled as u8) }
 }
 
+pub fn _set_bracketed_paste(_: bool) -> i32 {
+    ::ERR // PDCurses reads console events rather than the terminal's byte stream
+}
+
+pub fn _set_escdelay(_: i32) -> i32 {
+    ::ERR // Not supported
+}
+
+pub fn _set_mouse_motion_tracking(_: bool) -> i32 {
+    ::OK // The console reports motion by itself once the mask asks for it
+}
+
 pub fn _set_title(title: &str) {
     let s = CString::new(title).unwrap();
     unsafe { PDC_set_title(s.as_ptr()) }
@@ -101,6 +320,7 @@ pub fn to_special_keycode(i: i32) -> Option<Input> {
     match i {
         KEY_RESIZE => Some(Input::KeyResize),
         KEY_MOUSE => Some(Input::KeyMouse),
+        i if i > KEY_F15 && i <= KEY_F63 => Some(Input::KeyF((i - KEY_F15 + 15) as u8)),
 
         KEY_NUMPAD_UP => Some(Input::KeyUp),
         KEY_NUMPAD_DOWN => Some(Input::KeyDown),
@@ -132,48 +352,121 @@ pub fn to_special_keycode(i: i32) -> Option<Input> {
                 // Input is something else. This may require more processing to convert properly into utf8
                 None
             } else {
-                Some(SPECIAL_KEY_CODES[index as usize])
+                Some(SPECIAL_KEY_CODES[index as usize].clone())
             }
         }
     }
 }
 
+/// Converts a single integer returned by getch() to an Input without reading any further input.
+/// Half of a surrogate pair comes back as Unknown.
+pub fn from_keycode(i: i32) -> Input {
+    match to_special_keycode(i) {
+        Some(input) => input,
+        None => char::from_u32(i as u32)
+            .filter(|_| i <= 0xffff)
+            .map_or(Input::Unknown(i), Input::Character),
+    }
+}
+
+/// Converts an Input back to the integer getch() returns for it, the inverse of from_keycode().
+/// Returns None for characters that getch() returns as a surrogate pair.
+pub fn to_keycode(input: &Input) -> Option<i32> {
+    match input {
+        Input::Character(c) if (*c as u32) < 0x10000 => Some(*c as i32),
+        Input::Character(_) => None,
+        Input::Unknown(i) => Some(*i),
+        Input::KeyResize => Some(KEY_RESIZE),
+        Input::KeyMouse => Some(KEY_MOUSE),
+        Input::KeyF(n) if *n <= 63 => Some(KEY_F15 - 15 + i32::from(*n)),
+        Input::KeyF(_) => None,
+        Input::Resize { .. } => Some(KEY_RESIZE),
+        special => SPECIAL_KEY_CODES
+            .iter()
+            .position(|skc| skc == special)
+            .map(|index| {
+                let result = index as c_int + KEY_OFFSET;
+                if result <= KEY_F15 {
+                    result
+                } else {
+                    result + 48
+                }
+            }),
+    }
+}
+
+pub fn _tigetflag(_: &str) -> Option<bool> {
+    None // Not supported, PDCurses has no terminfo
+}
+
+pub fn _tigetnum(_: &str) -> Option<i32> {
+    None // Not supported, PDCurses has no terminfo
+}
+
+pub fn _tigetstr(_: &str) -> Option<Vec<u8>> {
+    None // Not supported, PDCurses has no terminfo
+}
+
+pub fn _tparm1(_: &[u8], _: i32) -> Option<Vec<u8>> {
+    None // Not supported, PDCurses has no terminfo
+}
+
+pub fn _unget_wch(c: char) -> i32 {
+    _ungetch(&Input::Character(c))
+}
+
+pub fn _wget_wch(w: *mut WINDOW) -> Option<Input> {
+    // getch() already assembles whole characters from PDCurses' UTF-16 input
+    _wgetch(w)
+}
+
 pub fn _wgetch(w: *mut WINDOW) -> Option<Input> {
     let i = unsafe { wgetch(w) };
     if i < 0 {
         None
     } else {
-        Some(to_special_keycode(i).unwrap_or_else(|| {
-            // Assume that on Windows input is UTF-16
-            // If decoding the single input value fails, it should mean that it is the leading part of a
-            // surrogate pair so calling getch() again should return the trailing part
-
-            decode_utf16(iter::once(i as u16))
-                .map(|result| {
-                    result
-                        .map(Input::Character)
-                        .unwrap_or_else(|first_error| {
-                            let trailing = unsafe { wgetch(w) };
-                            let data = [i as u16, trailing as u16];
-                            decode_utf16(data.iter().cloned())
-                                .map(|result| {
-                                    result.map(Input::Character).unwrap_or_else(
-                                        |second_error| {
-                                            warn!("Decoding input as UTF-16 failed. The two values that could not be decoded were {} and {}.", first_error.unpaired_surrogate(), second_error.unpaired_surrogate());
-                                            Input::Unknown(second_error.unpaired_surrogate() as i32)
-                                        },
-                                    )
-                                })
-                                .next()
-                                .unwrap()
-                        })
-                })
-                .next()
-                .unwrap()
-        }))
+        Some(decode_input(w, i))
     }
 }
 
+pub fn _wgetch_result(w: *mut WINDOW) -> Result<Option<Input>, i32> {
+    // PDCurses only returns ERR from wgetch() when no key arrived in time
+    Ok(_wgetch(w))
+}
+
+/// Converts a value returned by wgetch() to an Input, reading the rest of a surrogate pair from
+/// the window if needed.
+fn decode_input(w: *mut WINDOW, i: i32) -> Input {
+    to_special_keycode(i).unwrap_or_else(|| {
+        // Assume that on Windows input is UTF-16
+        // If decoding the single input value fails, it should mean that it is the leading part of a
+        // surrogate pair so calling getch() again should return the trailing part
+
+        decode_utf16(iter::once(i as u16))
+            .map(|result| {
+                result
+                    .map(Input::Character)
+                    .unwrap_or_else(|first_error| {
+                        let trailing = unsafe { wgetch(w) };
+                        let data = [i as u16, trailing as u16];
+                        decode_utf16(data.iter().cloned())
+                            .map(|result| {
+                                result.map(Input::Character).unwrap_or_else(
+                                    |second_error| {
+                                        warn!("Decoding input as UTF-16 failed. The two values that could not be decoded were {} and {}.", first_error.unpaired_surrogate(), second_error.unpaired_surrogate());
+                                        Input::Unknown(second_error.unpaired_surrogate() as i32)
+                                    },
+                                )
+                            })
+                            .next()
+                            .unwrap()
+                    })
+            })
+            .next()
+            .unwrap()
+    })
+}
+
 pub fn _ungetch(input: &Input) -> i32 {
     match *input {
         Input::Character(c) => {
@@ -185,22 +478,10 @@ pub fn _ungetch(input: &Input) -> i32 {
                 .map(|x| unsafe { PDC_ungetch(*x as c_int) })
                 .fold(0, cmp::min)
         }
-        Input::Unknown(i) => unsafe { PDC_ungetch(i) },
-        Input::KeyResize => unsafe { PDC_ungetch(KEY_RESIZE) },
-        Input::KeyMouse => unsafe { PDC_ungetch(KEY_MOUSE) },
-        specialKeyCode => {
-            for (i, skc) in SPECIAL_KEY_CODES.iter().enumerate() {
-                if *skc == specialKeyCode {
-                    let result = i as c_int + KEY_OFFSET;
-                    if result <= KEY_F15 {
-                        return unsafe { PDC_ungetch(result) };
-                    } else {
-                        return unsafe { PDC_ungetch(result + 48) };
-                    }
-                }
-            }
-            panic!("Failed to convert Input back to a c_int");
-        }
+        _ => match to_keycode(input) {
+            Some(code) => unsafe { PDC_ungetch(code) },
+            None => ::ERR,
+        },
     }
 }
 
@@ -227,6 +508,25 @@ mod tests {
         assert_eq!(Input::KeyUp, to_special_keycode(KEY_OFFSET + 3).unwrap());
     }
 
+    #[test]
+    fn test_keycode_round_trip() {
+        let inputs = SPECIAL_KEY_CODES
+            .iter()
+            .cloned()
+            .chain((16..=63).map(Input::KeyF))
+            .chain(vec![Input::KeyResize, Input::KeyMouse, Input::Unknown(0x20000)])
+            .chain(['a', '\n', 'ä', '€', '日'].iter().cloned().map(Input::Character));
+        for input in inputs {
+            let code = to_keycode(&input).unwrap();
+            assert_eq!(input, from_keycode(code));
+        }
+
+        assert_eq!(None, to_keycode(&Input::Character('𤭢')));
+        assert_eq!(None, to_keycode(&Input::Paste("text".to_string())));
+        assert_eq!(Input::Unknown(0xd852), from_keycode(0xd852));
+        assert_eq!(Input::KeyUp, from_keycode(KEY_NUMPAD_UP));
+    }
+
     #[test]
     fn test_ungetch() {
         let w = unsafe { initscr() };
@@ -247,6 +547,13 @@ mod tests {
             assert_eq!(_wgetch(w).unwrap(), *i);
         });
 
+        [Input::KeyF13, Input::KeyF(16), Input::KeyF(24), Input::KeyF(63)]
+            .iter()
+            .for_each(|i| {
+                _ungetch(i);
+                assert_eq!(_wgetch(w).unwrap(), *i);
+            });
+
         unsafe {
             endwin();
         }